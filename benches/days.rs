@@ -0,0 +1,63 @@
+//! Benchmarks for parsing and solving each day against its checked-in `input.txt`
+//!
+//! Each day gets its own group with `parse`, `part1` and `part2` benchmarks, so a single phase
+//! can be selected with e.g. `cargo bench -- day15/part2`. Days without an `input.txt` are
+//! skipped.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use aoc2022::input::Source;
+use aoc2022::Solution;
+use aoc2022::{
+    day1::Day1, day10::Day10, day11::Day11, day12::Day12, day13::Day13, day14::Day14, day15::Day15,
+    day2::Day2, day3::Day3, day4::Day4, day5::Day5, day6::Day6, day7::Day7, day8::Day8, day9::Day9,
+};
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let input = match Source::Default(S::DAY).read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("skipping day {}: {}", S::DAY, e);
+            return;
+        }
+    };
+    let parsed = match S::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("skipping day {}: {}", S::DAY, e);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<Day1>(c);
+    bench_day::<Day2>(c);
+    bench_day::<Day3>(c);
+    bench_day::<Day4>(c);
+    bench_day::<Day5>(c);
+    bench_day::<Day6>(c);
+    bench_day::<Day7>(c);
+    bench_day::<Day8>(c);
+    bench_day::<Day9>(c);
+    bench_day::<Day10>(c);
+    bench_day::<Day11>(c);
+    bench_day::<Day12>(c);
+    bench_day::<Day13>(c);
+    bench_day::<Day14>(c);
+    bench_day::<Day15>(c);
+}
+
+criterion_group! {
+    name = benches;
+    // Day 15's part 2 takes a few hundred milliseconds per iteration, so keep the sample count
+    // low enough for the whole suite to finish in reasonable time.
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
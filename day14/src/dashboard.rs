@@ -0,0 +1,149 @@
+//! A page for every day of the `aoc2022` library, solving pasted or uploaded input in the browser.
+//!
+//! The solvers are compiled to WASM along with the rest of the app, so nothing is sent to the
+//! server: pressing "Solve" runs the day's [`Solver`] on the client and shows its answers and
+//! timings.
+use aoc2022::runner::{self, Outcome, Run, Solver, SOLVERS};
+use leptos::*;
+use leptos_router::*;
+use wasm_bindgen_futures::JsFuture;
+
+/// Links to the home page and every day's page
+#[component]
+pub fn Days(cx: Scope) -> impl IntoView {
+    view! { cx,
+        <nav class="days">
+            <A href="/">"Home"</A>
+            {SOLVERS
+                .iter()
+                .map(|solver| view! { cx, <A href=format!("/day/{}", solver.day)>{solver.day}</A> })
+                .collect::<Vec<_>>()}
+        </nav>
+    }
+}
+
+/// Every implemented day, by title
+#[component]
+pub fn HomePage(cx: Scope) -> impl IntoView {
+    view! { cx,
+        <Days/>
+        <h1>"AOC 2022"</h1>
+        <ul class="titles">
+            {SOLVERS
+                .iter()
+                .map(|solver| view! { cx,
+                    <li>
+                        <A href=format!("/day/{}", solver.day)>
+                            {format!("Day {}: {}", solver.day, solver.title)}
+                        </A>
+                    </li>
+                })
+                .collect::<Vec<_>>()}
+        </ul>
+        <p><A href="/sand">"Watch the sand fall in day 14"</A></p>
+    }
+}
+
+/// The day named by the route's `:day` parameter, with input to solve and the latest results
+#[component]
+pub fn DayPage(cx: Scope) -> impl IntoView {
+    let params = use_params_map(cx);
+    let solver = move || -> Option<&'static Solver> {
+        let day = params.with(|params| params.get("day").and_then(|day| day.parse().ok()))?;
+        runner::solver(day)
+    };
+
+    let (input, set_input) = create_signal(cx, String::new());
+    let (run, set_run) = create_signal(cx, None::<Run>);
+
+    // Results belong to the day they were solved for
+    create_effect(cx, move |_| {
+        solver();
+        set_run.set(None);
+    });
+
+    let upload = move |ev: ev::Event| {
+        let target: web_sys::HtmlInputElement = event_target(&ev);
+        let Some(file) = target.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        spawn_local(async move {
+            if let Ok(text) = JsFuture::from(file.text()).await {
+                set_input.set(text.as_string().unwrap_or_default());
+            }
+        });
+    };
+
+    let solve = move |_| {
+        if let Some(solver) = solver() {
+            set_run.set(Some(input.with(|input| solver.run(input, None))));
+        }
+    };
+
+    view! { cx,
+        <Days/>
+        {move || match solver() {
+            Some(solver) => view! { cx,
+                <h1>{format!("Day {}: {}", solver.day, solver.title)}</h1>
+            },
+            None => view! { cx, <h1>"Not implemented"</h1> },
+        }}
+        <div class="input">
+            <textarea
+                rows="20"
+                cols="80"
+                placeholder="Paste puzzle input here, or upload it below"
+                prop:value=input
+                on:input=move |ev| set_input.set(event_target_value(&ev))
+            />
+            <div>
+                <input type="file" accept=".txt,text/plain" on:change=upload/>
+                <button on:click=solve disabled=move || solver().is_none()>"Solve"</button>
+            </div>
+        </div>
+        {move || run.get().map(|run| view! { cx, <Results run=run/> })}
+    }
+}
+
+/// A table of the parse time and each part's answer (or error) and solve time
+#[component]
+fn Results(cx: Scope, run: Run) -> impl IntoView {
+    let Run {
+        parse,
+        error,
+        report,
+        part1,
+        part2,
+        ..
+    } = run;
+    // Show parse errors in context, underlined in the offending line
+    let error = report.map(|report| report.render("input")).or(error);
+
+    let part = move |number: u8, outcome: Option<Outcome>| {
+        outcome.map(|outcome| {
+            let (answer, class) = match outcome.answer {
+                Ok(answer) => (answer, "answer"),
+                Err(e) => (e, "error"),
+            };
+            view! { cx,
+                <tr>
+                    <th>{format!("Part {}", number)}</th>
+                    <td><pre class=class>{answer}</pre></td>
+                    <td>{format!("{:.1?}", outcome.elapsed)}</td>
+                </tr>
+            }
+        })
+    };
+
+    view! { cx,
+        <table class="results">
+            <tr>
+                <th>"Parse"</th>
+                <td>{error.map(|e| view! { cx, <pre class="error">{e}</pre> })}</td>
+                <td>{format!("{:.1?}", parse)}</td>
+            </tr>
+            {part(1, part1)}
+            {part(2, part2)}
+        </table>
+    }
+}
@@ -1,4 +1,5 @@
 pub mod app;
+pub mod dashboard;
 use cfg_if::cfg_if;
 
 cfg_if! {
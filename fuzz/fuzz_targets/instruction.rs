@@ -0,0 +1,15 @@
+//! Day 10 instructions, alone and as whole programs
+#![no_main]
+
+use std::str::FromStr;
+
+use aoc2022::day10::{Day10, Instruction};
+use aoc2022::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        let _ = Instruction::from_str(s);
+        let _ = Day10::parse(s);
+    }
+});
@@ -0,0 +1,15 @@
+//! Day 11 monkey descriptions, alone and as whole notes
+#![no_main]
+
+use std::str::FromStr;
+
+use aoc2022::day11::{Day11, Monkey};
+use aoc2022::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        let _ = Monkey::from_str(s);
+        let _ = Day11::parse(s);
+    }
+});
@@ -0,0 +1,12 @@
+//! Day 9 lists of motions
+#![no_main]
+
+use aoc2022::day9::Day9;
+use aoc2022::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        let _ = Day9::parse(s);
+    }
+});
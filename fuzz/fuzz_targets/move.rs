@@ -0,0 +1,15 @@
+//! Day 5 moves, alone and as whole rearrangement procedures
+#![no_main]
+
+use std::str::FromStr;
+
+use aoc2022::day5::{Day5, Move};
+use aoc2022::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        let _ = Move::from_str(s);
+        let _ = Day5::parse(s);
+    }
+});
@@ -0,0 +1,15 @@
+//! Day 4 section ranges, alone and as whole assignment lists
+#![no_main]
+
+use std::str::FromStr;
+
+use aoc2022::day4::{Day4, SectionRange};
+use aoc2022::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        let _ = SectionRange::from_str(s);
+        let _ = Day4::parse(s);
+    }
+});
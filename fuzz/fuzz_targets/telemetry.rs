@@ -0,0 +1,15 @@
+//! Day 15 sensor reports, alone and as whole lists
+#![no_main]
+
+use std::str::FromStr;
+
+use aoc2022::day15::{Day15, Telemetry};
+use aoc2022::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        let _ = Telemetry::from_str(s);
+        let _ = Day15::parse(s);
+    }
+});
@@ -0,0 +1,132 @@
+//! Drawing [`Simulation`]s on a grid of characters, one frame per step.
+//!
+//! Each animated day draws whatever part of its state falls within a [`Frame`], a window onto
+//! the plane that scrolls to keep the simulation's focus in view. The `animate` binary shows the
+//! frames in a terminal; nothing here depends on how they are displayed.
+use std::fmt;
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::simulation::Simulation;
+
+/// A simulation that can be drawn on the plane
+pub trait Animation: Simulation {
+    /// The point to keep in view, such as the head of the rope
+    fn focus(&self) -> Point;
+
+    /// Draw the current state, covering every point of `frame`
+    fn draw(&self, frame: &mut Frame);
+
+    /// A line summarizing the current state
+    fn status(&self) -> String;
+}
+
+/// A window of characters onto the plane
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// The point drawn in the top left corner
+    origin: Point,
+    cells: Grid<char>,
+}
+
+impl Frame {
+    /// A blank `width` by `height` frame (at least 1 by 1) with the origin in its top left corner
+    pub fn new(width: usize, height: usize) -> Self {
+        Frame {
+            origin: Point::ORIGIN,
+            cells: Grid::new(width.max(1), height.max(1), ' '),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    /// The point drawn in the top left corner
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    /// Change the frame's size, keeping its top left corner and leaving it blank
+    pub fn resize(&mut self, width: usize, height: usize) {
+        self.cells = Grid::new(width.max(1), height.max(1), ' ');
+    }
+
+    /// Scroll so that `p` is in the middle of the frame
+    pub fn center(&mut self, p: Point) {
+        self.origin = p - Point::new(self.width() as i32 / 2, self.height() as i32 / 2);
+    }
+
+    /// Scroll as little as possible to keep `p` at least a quarter of the frame from its edges
+    pub fn follow(&mut self, p: Point) {
+        let follow = |origin: &mut i32, p: i32, size: usize| {
+            let margin = size as i32 / 4;
+            let last = size as i32 - 1 - margin;
+            if p < *origin + margin {
+                *origin = p - margin;
+            } else if p > *origin + last {
+                *origin = p - last;
+            }
+        };
+        let (width, height) = (self.width(), self.height());
+        follow(&mut self.origin.x, p.x, width);
+        follow(&mut self.origin.y, p.y, height);
+    }
+
+    /// Draw `c` at `p`, if it is in view
+    pub fn draw(&mut self, p: Point, c: char) {
+        if let Some(cell) = (p - self.origin)
+            .position()
+            .and_then(|position| self.cells.get_mut(position))
+        {
+            *cell = c;
+        }
+    }
+
+    /// Draw every point in view with the character given by `f`
+    pub fn fill(&mut self, mut f: impl FnMut(Point) -> char) {
+        let origin = self.origin;
+        self.cells = Grid::from_fn(self.width(), self.height(), |position| {
+            f(origin + Point::from(position))
+        });
+    }
+}
+
+/// Renders each row of the frame on its own line
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_in_view() {
+        let mut frame = Frame::new(3, 2);
+        frame.center(Point::new(10, 10));
+        frame.fill(|p| if p.y == 10 { '-' } else { '.' });
+        frame.draw(Point::new(10, 10), '@');
+        frame.draw(Point::new(20, 10), '#');
+        assert_eq!(frame.origin(), Point::new(9, 9));
+        assert_eq!(frame.to_string(), "...\n-@-");
+    }
+
+    #[test]
+    fn follows_focus() {
+        let mut frame = Frame::new(8, 4);
+        // A quarter of the frame is a margin of 2 columns and 1 row
+        frame.follow(Point::new(5, 2));
+        assert_eq!(frame.origin(), Point::ORIGIN);
+        frame.follow(Point::new(6, 3));
+        assert_eq!(frame.origin(), Point::new(1, 1));
+        frame.follow(Point::new(-4, 1));
+        assert_eq!(frame.origin(), Point::new(-6, 0));
+    }
+}
@@ -0,0 +1,265 @@
+//! Known-good answers for the checked-in inputs, so regressions can be caught with `aoc --check`.
+//!
+//! Answers are recorded in `answers.toml`, one table per day with a key per part:
+//!
+//! ```toml
+//! [day5]
+//! part1 = "VQZNJMWTR"
+//! part2 = "NLCDCLVMQ"
+//! ```
+//!
+//! Numeric answers may be written as integers, and multi-line answers (such as day 10's CRT
+//! image) as multi-line strings.
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::runner::{Part, Run};
+
+/// Recorded answers, keyed by day and part
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl Answers {
+    /// Read answers from a TOML file
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let contents = fs::read_to_string(path).map_err(|error| AnswersError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        Self::parse(&contents)
+    }
+
+    /// Parse answers from TOML, rejecting anything that isn't a `[dayN]` table of `partN` answers
+    pub fn parse(s: &str) -> Result<Self, AnswersError> {
+        let table: toml::Table = s.parse().map_err(AnswersError::Toml)?;
+        let mut answers = BTreeMap::new();
+
+        for (day_key, parts) in table {
+            let invalid = |reason: &str| AnswersError::Invalid {
+                key: day_key.clone(),
+                reason: reason.to_string(),
+            };
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| invalid("expected a `[dayN]` table"))?;
+            let toml::Value::Table(parts) = parts else {
+                return Err(invalid("expected a `[dayN]` table"));
+            };
+
+            for (part_key, answer) in parts {
+                let invalid = |reason: &str| AnswersError::Invalid {
+                    key: format!("{}.{}", day_key, part_key),
+                    reason: reason.to_string(),
+                };
+                let part = part_key
+                    .strip_prefix("part")
+                    .and_then(|part| part.parse::<Part>().ok())
+                    .ok_or_else(|| invalid("expected `part1` or `part2`"))?;
+                let answer = match answer {
+                    toml::Value::String(answer) => answer,
+                    toml::Value::Integer(answer) => answer.to_string(),
+                    _ => return Err(invalid("expected a string or integer answer")),
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    /// The recorded answer to one part of a day, if there is one
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Record the answer to one part of a day, replacing any answer already recorded
+    pub fn insert(&mut self, day: u8, part: Part, answer: &str) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    /// The answers as TOML, in the format [`Answers::parse`] reads, by day then part
+    ///
+    /// Answers that are integers are written as integers, and everything else as strings.
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        let mut last_day = None;
+        for (&(day, part), answer) in &self.answers {
+            if last_day != Some(day) {
+                if last_day.is_some() {
+                    toml.push('\n');
+                }
+                toml.push_str(&format!("[day{}]\n", day));
+                last_day = Some(day);
+            }
+            let value = match answer.parse::<i64>() {
+                Ok(n) => toml::Value::Integer(n),
+                Err(_) => toml::Value::String(answer.clone()),
+            };
+            toml.push_str(&format!("part{} = {}\n", part.number(), value));
+        }
+        toml
+    }
+
+    /// Compare each part solved in `run` against its recorded answer
+    pub fn check(&self, run: &Run) -> Vec<(Part, Verdict)> {
+        [(Part::One, &run.part1), (Part::Two, &run.part2)]
+            .into_iter()
+            .filter_map(|(part, outcome)| {
+                let verdict = match (&outcome.as_ref()?.answer, self.get(run.day, part)) {
+                    (Err(e), _) => Verdict::Failed(e.clone()),
+                    (Ok(_), None) => Verdict::Unrecorded,
+                    (Ok(actual), Some(expected)) if actual == expected => Verdict::Match,
+                    (Ok(actual), Some(expected)) => Verdict::Mismatch {
+                        expected: expected.to_string(),
+                        actual: actual.clone(),
+                    },
+                };
+                Some((part, verdict))
+            })
+            .collect()
+    }
+}
+
+/// How a solved part compares to its recorded answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// There is no recorded answer to compare against
+    Unrecorded,
+    /// The part couldn't be solved at all
+    Failed(String),
+}
+
+/// The well-known location of the answers file: `answers.toml` in the root of this crate
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+/// Failure to read or understand an answers file
+#[derive(Debug)]
+pub enum AnswersError {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Toml(toml::de::Error),
+    /// Valid TOML that doesn't describe answers, naming the offending key
+    Invalid {
+        key: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Io { path, error } => {
+                write!(
+                    f,
+                    "could not read answers from `{}`: {}",
+                    path.display(),
+                    error
+                )
+            }
+            AnswersError::Toml(error) => write!(f, "invalid answers file: {}", error),
+            AnswersError::Invalid { key, reason } => {
+                write!(f, "invalid answers file: `{}`: {}", key, reason)
+            }
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AnswersError::Io { error, .. } => Some(error),
+            AnswersError::Toml(error) => Some(error),
+            AnswersError::Invalid { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner;
+
+    #[test]
+    fn parse_answers() {
+        let answers = Answers::parse(
+            "[day1]\npart1 = 24000\npart2 = \"45000\"\n\n[day10]\npart2 = \"\"\"\n##\n..\"\"\"\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("24000"));
+        assert_eq!(answers.get(1, Part::Two), Some("45000"));
+        assert_eq!(answers.get(10, Part::One), None);
+        assert_eq!(answers.get(10, Part::Two), Some("##\n.."));
+    }
+
+    #[test]
+    fn answers_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(10, Part::Two, "##\n..");
+        answers.insert(2, Part::One, "15");
+        answers.insert(5, Part::Two, "MCD");
+        answers.insert(2, Part::Two, "12");
+        let toml = answers.to_toml();
+        assert_eq!(
+            toml,
+            "[day2]\npart1 = 15\npart2 = 12\n\n[day5]\npart2 = \"MCD\"\n\n[day10]\npart2 = \"\"\"\n##\n..\"\"\"\n"
+        );
+        assert_eq!(Answers::parse(&toml).unwrap(), answers);
+    }
+
+    #[test]
+    fn invalid_answers() {
+        let err = Answers::parse("[day1]\npart3 = 1\n").unwrap_err();
+        assert!(matches!(err, AnswersError::Invalid { key, .. } if key == "day1.part3"));
+        let err = Answers::parse("[dayone]\npart1 = 1\n").unwrap_err();
+        assert!(matches!(err, AnswersError::Invalid { key, .. } if key == "dayone"));
+    }
+
+    #[test]
+    fn check_run() {
+        let answers = Answers::parse("[day1]\npart1 = 24000\npart2 = 1\n").unwrap();
+        let example = include_str!("bin/day1/example.txt");
+        let run = runner::solver(1).unwrap().run(example, None);
+        assert_eq!(
+            answers.check(&run),
+            vec![
+                (Part::One, Verdict::Match),
+                (
+                    Part::Two,
+                    Verdict::Mismatch {
+                        expected: "1".to_string(),
+                        actual: "45000".to_string()
+                    }
+                ),
+            ]
+        );
+    }
+
+    /// Every recorded answer must belong to an implemented day
+    #[test]
+    fn recorded_answers() {
+        let answers = Answers::load(&default_path()).unwrap();
+        for &(day, _) in answers.answers.keys() {
+            assert!(
+                runner::solver(day).is_some(),
+                "day {} is not implemented",
+                day
+            );
+        }
+    }
+}
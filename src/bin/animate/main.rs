@@ -0,0 +1,230 @@
+/// Terminal animation of a day's simulation
+///
+/// Usage: animate DAY [PART] [--input PATH|-]
+///
+/// Plays day 9's rope, day 12's search or day 14's falling sand in the terminal, one step at a
+/// time, following the head of the rope, the search's latest position or the latest grain of sand.
+/// PART selects the puzzle part to animate (1 by default), and input is read as for `aoc`.
+///
+/// Space pauses and resumes, `n` or the right arrow takes a single step (pausing first), `+` and
+/// `-` double or halve the speed, and `q` or Esc quits.
+use std::env;
+use std::error::Error;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+use aoc2022::animation::{Animation, Frame};
+use aoc2022::day12::{Day12, Search};
+use aoc2022::day14::{Day14, Sand};
+use aoc2022::day9::{Day9, Rope};
+use aoc2022::input::Source;
+use aoc2022::runner::Part;
+use aoc2022::Solution;
+
+const USAGE: &str = "Usage: animate DAY [PART] [--input PATH|-]";
+
+/// How long each frame is shown for
+const FRAME: Duration = Duration::from_millis(33);
+
+/// Steps per second at the start, and the most that can be asked for
+const INITIAL_SPEED: u32 = 64;
+const MAX_SPEED: u32 = 1 << 16;
+
+/// Lines below the frame, for the status and the keys
+const FOOTER: u16 = 2;
+
+#[derive(Debug)]
+struct Args {
+    day: u8,
+    part: Part,
+    input: Option<String>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut part = None;
+        let mut input = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-i" | "--input" => {
+                    let path = args.next().ok_or(format!("{} requires a path", arg))?;
+                    input = Some(path);
+                }
+                arg if day.is_none() => {
+                    let parsed = arg.parse().map_err(|_| format!("invalid day '{}'", arg))?;
+                    day = Some(parsed);
+                }
+                arg if part.is_none() => {
+                    let parsed = arg.parse().map_err(|_| format!("invalid part '{}'", arg))?;
+                    part = Some(parsed);
+                }
+                unexpected => return Err(format!("unexpected argument '{}'", unexpected)),
+            }
+        }
+
+        Ok(Args {
+            day: day.ok_or("a day is required")?,
+            part: part.unwrap_or(Part::One),
+            input,
+        })
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parse the day's input and animate the simulation for the selected part
+fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let input = Source::new(args.day, args.input.as_deref()).read()?;
+
+    match (args.day, args.part) {
+        (9, part) => {
+            let motions = Day9::parse(&input)?;
+            let n_knots = match part {
+                Part::One => 2,
+                Part::Two => 10,
+            };
+            animate(Rope::new(&motions, n_knots))
+        }
+        (12, Part::One) => {
+            let heightmap = Day12::parse(&input)?;
+            animate(Search::new(&heightmap, |node| {
+                node.coordinates == heightmap.start
+            }))
+        }
+        (12, Part::Two) => {
+            let heightmap = Day12::parse(&input)?;
+            animate(Search::new(&heightmap, |node| node.height == b'a'))
+        }
+        (14, part) => {
+            let cave = Day14::parse(&input)?;
+            animate(Sand::new(&cave, part == Part::Two))
+        }
+        (day, _) => Err(format!("no animation for day {}", day).into()),
+    }
+}
+
+/// Play `animation` on the terminal's alternate screen, restoring the terminal afterwards
+fn animate<A>(animation: A) -> Result<(), Box<dyn Error>>
+where
+    A: Animation,
+    A::Error: Error + 'static,
+{
+    let mut out = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(out, EnterAlternateScreen, Hide)?;
+
+    let result = play(&mut out, animation);
+
+    execute!(out, Show, LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+/// Step through `animation` at the chosen speed, drawing a frame at a time, until the user quits
+fn play<A>(out: &mut impl Write, mut animation: A) -> Result<(), Box<dyn Error>>
+where
+    A: Animation,
+    A::Error: Error + 'static,
+{
+    let (columns, rows) = terminal::size()?;
+    let mut frame = Frame::new(columns as usize, rows.saturating_sub(FOOTER) as usize);
+    frame.center(animation.focus());
+
+    let mut speed = INITIAL_SPEED;
+    let mut paused = false;
+    // Steps due at the current speed but not yet taken, carried over between frames
+    let mut due = 0.0;
+    let mut last = Instant::now();
+
+    loop {
+        frame.follow(animation.focus());
+        animation.draw(&mut frame);
+        let state = match (animation.is_done(), paused) {
+            (true, _) => "done",
+            (false, true) => "paused",
+            (false, false) => "running",
+        };
+        let keys = format!(
+            "{} at {} steps/s | space: pause, n: step, +/-: speed, q: quit",
+            state, speed
+        );
+        render(out, &frame, &[&animation.status(), &keys])?;
+
+        if event::poll(FRAME)? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        return Ok(())
+                    }
+                    KeyCode::Char(' ') => paused = !paused,
+                    KeyCode::Char('n') | KeyCode::Right => {
+                        paused = true;
+                        animation.step()?;
+                    }
+                    KeyCode::Char('+') | KeyCode::Char('=') => speed = (speed * 2).min(MAX_SPEED),
+                    KeyCode::Char('-') => speed = (speed / 2).max(1),
+                    _ => {}
+                },
+                Event::Resize(columns, rows) => {
+                    frame.resize(columns as usize, rows.saturating_sub(FOOTER) as usize);
+                    frame.center(animation.focus());
+                }
+                _ => {}
+            }
+        }
+
+        let now = Instant::now();
+        if !paused {
+            due += speed as f64 * (now - last).as_secs_f64();
+        }
+        last = now;
+        while due >= 1.0 && !animation.is_done() {
+            animation.step()?;
+            due -= 1.0;
+        }
+        due = due.fract();
+    }
+}
+
+/// Draw `frame` at the top of the screen with `footer` below it, cut to the frame's width
+fn render(out: &mut impl Write, frame: &Frame, footer: &[&str]) -> io::Result<()> {
+    let frame = frame.to_string();
+    let width = frame.lines().next().map_or(0, str::len);
+    let lines = frame.lines().chain(footer.iter().copied());
+    for (row, line) in lines.enumerate() {
+        let line: String = line.chars().take(width).collect();
+        queue!(
+            out,
+            MoveTo(0, row as u16),
+            Print(line),
+            Clear(ClearType::UntilNewLine)
+        )?;
+    }
+    out.flush()
+}
@@ -0,0 +1,10 @@
+/// Day 14: Regolith Reservoir
+///
+/// Usage: day14 [PATH|-]
+use std::process::ExitCode;
+
+use aoc2022::day14::Day14;
+
+pub fn main() -> ExitCode {
+    aoc2022::run_main::<Day14>()
+}
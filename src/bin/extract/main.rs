@@ -0,0 +1,172 @@
+/// Turn a saved puzzle page into test fixtures
+///
+/// Usage: extract PAGE [--day DAY] [--block N] [--force]
+///
+/// Reads a puzzle page saved from the Advent of Code site, writes its example input to the day's
+/// `src/bin/dayN/example.txt` and records the answers each unlocked part gives for it in
+/// `examples.toml`, where the tests check them. The day is taken from the page unless given.
+///
+/// The example is the first `<pre><code>` block on the page unless `--block` picks another, and
+/// every block is listed so the right one can be picked. An existing example or answer that's
+/// different is left alone unless `--force` is given.
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc2022::answers::{Answers, AnswersError};
+use aoc2022::examples::{self, Page};
+use aoc2022::input;
+
+const USAGE: &str = "Usage: extract PAGE [--day DAY] [--block N] [--force]";
+
+#[derive(Debug)]
+struct Args {
+    page: PathBuf,
+    day: Option<u8>,
+    block: usize,
+    force: bool,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut page = None;
+        let mut day = None;
+        let mut block = 1;
+        let mut force = false;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => {
+                    let value = args.next().ok_or(format!("{} requires a day", arg))?;
+                    match value.parse() {
+                        Ok(parsed @ 1..=25) => day = Some(parsed),
+                        _ => return Err(format!("invalid day '{}'", value)),
+                    }
+                }
+                "--block" => {
+                    let value = args.next().ok_or(format!("{} requires a number", arg))?;
+                    match value.parse() {
+                        Ok(parsed @ 1..) => block = parsed,
+                        _ => return Err(format!("invalid block '{}'", value)),
+                    }
+                }
+                "--force" => force = true,
+                arg if page.is_none() => page = Some(PathBuf::from(arg)),
+                unexpected => return Err(format!("unexpected argument '{}'", unexpected)),
+            }
+        }
+
+        Ok(Args {
+            page: page.ok_or("a saved page is required")?,
+            day,
+            block,
+            force,
+        })
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match extract(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn extract(args: &Args) -> Result<(), Box<dyn Error>> {
+    let html = fs::read_to_string(&args.page)
+        .map_err(|e| format!("could not read `{}`: {}", args.page.display(), e))?;
+    let page = Page::parse(&html);
+    let day = args
+        .day
+        .or(page.day)
+        .ok_or("the page has no day in its title, so give one with --day")?;
+
+    let blocks: Vec<&String> = page.descriptions.iter().flat_map(|d| &d.blocks).collect();
+    let plural = if blocks.len() == 1 { "" } else { "s" };
+    println!("Day {}: {} example block{}", day, blocks.len(), plural);
+    for (i, block) in blocks.iter().enumerate() {
+        let first = block.lines().next().unwrap_or_default();
+        let marker = if i + 1 == args.block { '*' } else { ' ' };
+        println!(
+            "{} {:>2}: {:>3} lines, `{}`",
+            marker,
+            i + 1,
+            block.lines().count(),
+            first
+        );
+    }
+
+    let example = blocks
+        .get(args.block - 1)
+        .ok_or(format!("the page has no block {}", args.block))?;
+    let path = input::default_path(day).with_file_name("example.txt");
+    let example_changed = match fs::read_to_string(&path) {
+        Ok(existing) if existing == **example => false,
+        Ok(_) if !args.force => {
+            return Err(format!("{} differs, use --force to replace it", path.display()).into())
+        }
+        Ok(_) | Err(_) => true,
+    };
+
+    let answers_path = examples::default_path();
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(AnswersError::Io { error, .. }) if error.kind() == io::ErrorKind::NotFound => {
+            Answers::default()
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let found = page.answers();
+    for &(part, answer) in &found {
+        match answers.get(day, part) {
+            Some(existing) if existing == answer => {}
+            Some(existing) if !args.force => {
+                return Err(format!(
+                    "part {} is recorded as {} rather than {}, use --force to replace it",
+                    part, existing, answer
+                )
+                .into())
+            }
+            _ => answers.insert(day, part, answer),
+        }
+    }
+
+    // Only write anything once nothing stands in the way of writing everything
+    if example_changed {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, example)?;
+        println!("Wrote {}", path.display());
+    } else {
+        println!("{} is unchanged", path.display());
+    }
+
+    if found.is_empty() {
+        println!("No emphasized answers found");
+        return Ok(());
+    }
+    for (part, answer) in found {
+        println!("Part {}: {}", part, answer);
+    }
+    fs::write(
+        &answers_path,
+        format!("{}{}", examples::HEADER, answers.to_toml()),
+    )?;
+    println!("Recorded answers in {}", answers_path.display());
+    Ok(())
+}
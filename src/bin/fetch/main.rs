@@ -0,0 +1,59 @@
+/// Download puzzle input
+///
+/// Usage: fetch [DAY|all]
+///
+/// Saves each day's input to `src/bin/dayN/input.txt`, where the other binaries read it from, for
+/// every implemented day by default. Input that's already there is left alone, so the site is
+/// only asked for each day once. Fetching needs the session cookie of a logged-in browser in the
+/// `AOC_SESSION` environment variable.
+use std::env;
+use std::process::ExitCode;
+
+use aoc2022::fetch::Fetcher;
+use aoc2022::runner::{self, SOLVERS};
+
+const USAGE: &str = "Usage: fetch [DAY|all]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let days: Vec<u8> = match args.as_slice() {
+        [] => SOLVERS.iter().map(|solver| solver.day).collect(),
+        [all] if all == "all" => SOLVERS.iter().map(|solver| solver.day).collect(),
+        [day] => match day.parse() {
+            Ok(day) if runner::solver(day).is_some() => vec![day],
+            _ => {
+                eprintln!("error: day {} is not implemented\n{}", day, USAGE);
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let fetcher = Fetcher::from_env();
+    let mut failed = false;
+    for day in days {
+        let path = fetcher.path(day);
+        let cached = fetcher.is_cached(day);
+        match fetcher.input(day) {
+            Ok(_) if cached => println!("Day {}: cached at {}", day, path.display()),
+            Ok(input) => println!(
+                "Day {}: fetched {} lines to {}",
+                day,
+                input.lines().count(),
+                path.display()
+            ),
+            Err(e) => {
+                eprintln!("Day {}: error: {}", day, e);
+                failed = true;
+            }
+        }
+    }
+
+    match failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
@@ -0,0 +1,36 @@
+/// Random puzzle input generator
+///
+/// Usage: generate DAY SEED SIZE
+///
+/// Prints a random but valid input for DAY to stdout, for stress-testing the solvers with
+/// `aoc DAY --input -`. The meaning of SIZE depends on the day (see [`aoc2022::generate`]); the
+/// same arguments always produce the same input.
+use std::env;
+use std::process::ExitCode;
+
+use aoc2022::generate;
+
+const USAGE: &str = "Usage: generate DAY SEED SIZE";
+
+pub fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let [day, seed, size] = args.as_slice() else {
+        eprintln!("{}", USAGE);
+        return ExitCode::from(2);
+    };
+    let (Ok(day), Ok(seed), Ok(size)) = (day.parse(), seed.parse(), size.parse()) else {
+        eprintln!("error: DAY, SEED and SIZE must be integers\n{}", USAGE);
+        return ExitCode::from(2);
+    };
+
+    match generate::input(day, seed, size) {
+        Some(input) => {
+            print!("{}", input);
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("error: no generator for day {}", day);
+            ExitCode::FAILURE
+        }
+    }
+}
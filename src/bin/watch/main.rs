@@ -0,0 +1,112 @@
+/// Re-solve a day whenever its input or examples change
+///
+/// Usage: watch DAY
+///
+/// Solves the day against its `input.txt` and every `example*.txt` next to it, then waits for
+/// any of them to be written (using inotify, so only on Linux) and solves that file again,
+/// showing its answers and how much each phase's time changed since the last run. Any answer that
+/// changed is shown with what it was.
+use std::process::ExitCode;
+
+#[cfg(target_os = "linux")]
+use {
+    aoc2022::input::{self, Source},
+    aoc2022::runner::{self, Run, Solver},
+    aoc2022::watch,
+    inotify::{Inotify, WatchMask},
+    std::collections::{BTreeSet, HashMap},
+    std::env,
+    std::error::Error,
+    std::path::{Path, PathBuf},
+};
+
+const USAGE: &str = "Usage: watch DAY";
+
+#[cfg(target_os = "linux")]
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let (Some(day), None) = (args.next(), args.next()) else {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    };
+    let Some(solver) = day.parse().ok().and_then(runner::solver) else {
+        eprintln!("error: day {} is not implemented\n{}", day, USAGE);
+        return ExitCode::FAILURE;
+    };
+
+    match watch_day(solver) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn main() -> ExitCode {
+    eprintln!(
+        "error: watch mode uses inotify, which is only available on Linux\n{}",
+        USAGE
+    );
+    ExitCode::FAILURE
+}
+
+/// Solve every watched file once, then again each time it's written, until interrupted
+#[cfg(target_os = "linux")]
+fn watch_day(solver: &Solver) -> Result<(), Box<dyn Error>> {
+    let input = input::default_path(solver.day);
+    let dir = input.parent().ok_or("input has no directory")?;
+
+    // Watch the directory rather than the files, so that editors which save by writing a new
+    // file and renaming it over the old one are still noticed
+    let mut inotify = Inotify::init()?;
+    inotify
+        .watches()
+        .add(dir, WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO)?;
+
+    let mut previous = HashMap::new();
+    for path in watch::files(dir)? {
+        solve(solver, &path, &mut previous);
+    }
+    println!("Watching {} for changes, Ctrl-C to stop", dir.display());
+
+    let mut buffer = [0; 4096];
+    loop {
+        // Saving one file can produce several events, so solve each changed file once per batch
+        let changed: BTreeSet<PathBuf> = inotify
+            .read_events_blocking(&mut buffer)?
+            .filter_map(|event| event.name.map(|name| dir.join(name)))
+            .filter(|path| watch::is_watched(path))
+            .collect();
+        for path in changed {
+            solve(solver, &path, &mut previous);
+        }
+    }
+}
+
+/// Solve the file at `path`, printing how its results compare with the last time it was solved
+#[cfg(target_os = "linux")]
+fn solve(solver: &Solver, path: &Path, previous: &mut HashMap<PathBuf, Run>) {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let source = Source::Path(path.to_path_buf());
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("\nerror: {}", e);
+            return;
+        }
+    };
+
+    let run = solver.run(&input, None);
+    print!("\n{}", watch::render(&name, previous.get(path), &run));
+    match (&run.report, run.error()) {
+        (Some(report), _) => eprint!("{}", report.render(&source.name())),
+        (None, Some(e)) => eprintln!("error: {}", e),
+        (None, None) => {}
+    }
+    // Only compare against runs that parsed, so fixing a typo shows the change from before it
+    if run.error.is_none() {
+        previous.insert(path.to_path_buf(), run);
+    }
+}
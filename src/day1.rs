@@ -0,0 +1,152 @@
+/// Day 1: Calorie Counting
+use std::fmt;
+
+use itertools::Itertools;
+
+use crate::error::{self, Diagnostic, Location};
+use crate::input::normalize;
+use crate::Solution;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Input = Vec<Inventory>;
+    type Part1 = i32;
+    type Part2 = i32;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        inventories(input)
+    }
+
+    fn part1(elves: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        elves
+            .iter()
+            .map(Inventory::calories)
+            .max()
+            .ok_or(Error::NoElves)
+    }
+
+    fn part2(elves: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        Ok(elves
+            .iter()
+            .map(Inventory::calories)
+            .sorted()
+            .rev()
+            .take(3)
+            .sum())
+    }
+}
+
+/// The calories of each item one elf is carrying
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Inventory {
+    pub items: Vec<i32>,
+}
+
+impl Inventory {
+    /// Total calories of the elf's items
+    pub fn calories(&self) -> i32 {
+        self.items.iter().sum()
+    }
+}
+
+impl fmt::Display for Inventory {
+    /// Write each item on its own line, as in the input
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.items.iter().join("\n"))
+    }
+}
+
+/// Each elf's inventory
+///
+/// Each elf's inventory is a run of lines containing a single integer, separated from the next
+/// elf's by a blank line.
+pub fn inventories(input: &str) -> Result<Vec<Inventory>, Error> {
+    let mut elves = vec![];
+    let mut inventory: Option<Inventory> = None;
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            elves.extend(inventory.take());
+            continue;
+        }
+        let item: i32 = line
+            .parse()
+            .map_err(|_| Error::InvalidCalories(Location::new(i, line, 0)))?;
+        inventory
+            .get_or_insert_with(Inventory::default)
+            .items
+            .push(item);
+    }
+    elves.extend(inventory);
+
+    Ok(elves)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't a single integer
+    InvalidCalories(Location),
+    /// Input without any inventory at all
+    NoElves,
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidCalories(_) => "expected a single integer".to_string(),
+            Error::NoElves => "input contains no elves".to_string(),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidCalories(loc) => Some(loc),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day1/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day1::part1(&Day1::parse(EXAMPLE).unwrap()), Ok(24000));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day1::part2(&Day1::parse(EXAMPLE).unwrap()), Ok(45000));
+    }
+
+    #[test]
+    fn invalid_calories() {
+        let err = Day1::parse("1000\n\n2000\n3x00\n").unwrap_err();
+        assert_eq!(err, Error::InvalidCalories(Location::new(3, "3x00", 0)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let elves = Day1::parse(EXAMPLE).unwrap();
+        let printed = format!("{}\n", elves.iter().join("\n\n"));
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(Day1::parse(&printed).unwrap(), elves);
+    }
+}
@@ -0,0 +1,261 @@
+/// Day 10: Cathode-Ray Tube
+use std::convert::Infallible;
+use std::fmt;
+use std::slice;
+use std::str::FromStr;
+
+use crate::error::{self, Diagnostic, Location};
+use crate::input::normalize;
+use crate::simulation::Simulation;
+use crate::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+
+    type Input = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = String;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, s)| {
+                Instruction::from_str(s)
+                    .map_err(|e| Error::InvalidInstruction(Location::new(i, s, e.offset)))
+            })
+            .collect()
+    }
+
+    /// Register
+    fn part1(program: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        let mut total_signal_strength = 0;
+        let Ok(()) = Cpu::new(program).run_observed(|&CpuState { cycle, x }| match cycle {
+            20 | 60 | 100 | 140 | 180 | 220 => total_signal_strength += cycle as i32 * x,
+            _ => {}
+        });
+
+        Ok(total_signal_strength)
+    }
+
+    /// CRT
+    fn part2(program: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        let mut cpu = Cpu::new(program);
+        const MAX_COLS: u64 = 40;
+        const MAX_ROWS: u64 = 6;
+        let mut screen = String::new();
+
+        loop {
+            // Draw pixel
+            let CpuState { cycle, x } = cpu.snapshot();
+            let col = (cycle - 1) % MAX_COLS;
+            match x {
+                x if ((col as i32 - 1)..=(col as i32 + 1)).contains(&x) => screen.push('#'),
+                _ => screen.push('.'),
+            }
+            if col == MAX_COLS - 1 {
+                if cycle == MAX_COLS * MAX_ROWS {
+                    break;
+                }
+                screen.push('\n');
+            }
+
+            // Advance CPU pipeline
+            if cpu.is_done() {
+                break;
+            }
+            let Ok(()) = cpu.step();
+        }
+
+        Ok(screen)
+    }
+}
+
+/// A CPU running a program, one clock cycle per step
+#[derive(Clone, Debug)]
+pub struct Cpu<'a> {
+    x: i32, // Register
+    pc: Option<Instruction>,
+    program: slice::Iter<'a, Instruction>,
+    cycle: u64,
+}
+
+/// The CPU's state during a clock cycle, counting from 1
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpuState {
+    pub cycle: u64,
+    pub x: i32,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        let mut program = program.iter();
+        Cpu {
+            x: 1,
+            pc: program.next().cloned(),
+            program,
+            cycle: 1,
+        }
+    }
+}
+
+impl Simulation for Cpu<'_> {
+    type Snapshot = CpuState;
+    type Error = Infallible;
+
+    fn step(&mut self) -> Result<(), Self::Error> {
+        match &mut self.pc {
+            Some(Instruction { op, cycle: 1 }) => {
+                match op {
+                    OpCode::Addx(addend) => self.x += *addend,
+                    OpCode::Noop => {}
+                }
+                self.pc = self.program.next().cloned();
+            }
+            Some(Instruction { cycle, .. }) => *cycle -= 1,
+            None => return Ok(()),
+        }
+        self.cycle += 1;
+        Ok(())
+    }
+
+    fn is_done(&self) -> bool {
+        self.pc.is_none()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        CpuState {
+            cycle: self.cycle,
+            x: self.x,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpCode {
+    Noop,
+    Addx(i32),
+}
+
+// Instructions with cycle count
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub op: OpCode,
+    pub cycle: i32,
+}
+
+/// An instruction that isn't `noop` or `addx <i32>`, failing at byte `offset`
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseInstructionError {
+    pub offset: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseInstructionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "noop" => Ok(Instruction {
+                op: OpCode::Noop,
+                cycle: 1,
+            }),
+            insn if insn.starts_with("addx") => {
+                let addend = insn
+                    .strip_prefix("addx ")
+                    .ok_or(ParseInstructionError { offset: 4 })?;
+                Ok(Instruction {
+                    op: OpCode::Addx(
+                        addend
+                            .parse()
+                            .map_err(|_| ParseInstructionError { offset: 5 })?,
+                    ),
+                    cycle: 2,
+                })
+            }
+            _ => Err(ParseInstructionError { offset: 0 }),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.op {
+            OpCode::Noop => write!(f, "noop"),
+            OpCode::Addx(addend) => write!(f, "addx {}", addend),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't `noop` or `addx <i32>`
+    InvalidInstruction(Location),
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidInstruction(_) => "expected `addx <i32>` or `noop`".to_string(),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidInstruction(loc) => Some(loc),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day10/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day10::part1(&Day10::parse(EXAMPLE).unwrap()), Ok(13140));
+    }
+
+    #[test]
+    fn part2_example() {
+        let expected = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        assert_eq!(
+            Day10::part2(&Day10::parse(EXAMPLE).unwrap()),
+            Ok(expected.to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        let err = Day10::parse("noop\naddx 3\naddx\n").unwrap_err();
+        assert_eq!(err, Error::InvalidInstruction(Location::new(2, "addx", 4)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let program = Day10::parse(EXAMPLE).unwrap();
+        let printed: String = program.iter().map(|insn| format!("{}\n", insn)).collect();
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(Day10::parse(&printed).unwrap(), program);
+    }
+}
@@ -0,0 +1,407 @@
+/// Day 11: Monkey in the Middle
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::fmt;
+use std::str::FromStr;
+
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, multispace0, one_of},
+    combinator::{consumed, cut, map, value, verify},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    IResult,
+};
+
+use crate::error::{self, Diagnostic, Location};
+use crate::input::normalize;
+use crate::parse::{blocks, comma_list, finish, labelled, line, unsigned};
+use crate::simulation::Simulation;
+use crate::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    type Input = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        let parser = terminated(blocks(consumed(monkey)), multispace0);
+        let monkeys: Vec<(&str, Monkey)> = finish(input, parser)
+            .map_err(|offset| Error::InvalidMonkey(Location::at(input, offset)))?;
+
+        let n_monkeys = monkeys.len();
+        for (block, monkey) in monkeys.iter() {
+            // The partners are named at the end of the last two lines of the description
+            let partner_lines = block.lines().skip(4);
+            for (line, partner) in partner_lines.zip([monkey.partners[1], monkey.partners[0]]) {
+                if partner as usize >= n_monkeys {
+                    let offset = line.as_ptr() as usize - input.as_ptr() as usize + line.len() - 1;
+                    return Err(Error::UnknownMonkey(Location::at(input, offset)));
+                }
+            }
+        }
+
+        Ok(monkeys.into_iter().map(|(_, monkey)| monkey).collect())
+    }
+
+    /// I am relieved after each inspection, so worry is divided by 3
+    fn part1(monkeys: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        monkey_business(monkeys.clone(), 20, |worry| worry / 3)
+    }
+
+    /// I am no longer relieved, so worry is kept manageable modulo the product of all tests
+    fn part2(monkeys: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        let lcm = monkeys
+            .iter()
+            .map(|m| m.divisor)
+            .try_fold(1, |a, b| lcm(a, b).ok_or(Error::WorryOverflow))?;
+        monkey_business(monkeys.clone(), 10000, |worry| worry % lcm)
+    }
+}
+
+// A "round" is:
+// 1. Monkey inspects each item: worry = operation(worry).
+// 2. I am relieved that the item is not damaged: worry = relief(worry).
+// 3. The monkeys test my worry level on each item they hold in order and throw it accordingly.
+// 4. An item thrown to a monkey is appended to the _end_ of its list.
+//
+// Monkey business is the product of the two highest inspection counts.
+pub fn monkey_business(
+    monkeys: Vec<Monkey>,
+    rounds: usize,
+    relief: impl Fn(u64) -> u64,
+) -> Result<usize, Error> {
+    let mut game = KeepAway::new(monkeys, rounds, relief);
+    game.run()?;
+    Ok(game.monkey_business())
+}
+
+/// Monkeys playing keep away with my items, one round per step
+#[derive(Clone, Debug)]
+pub struct KeepAway<F> {
+    monkeys: Vec<Monkey>,
+    relief: F,
+    round: usize,
+    rounds: usize,
+    n_inspections: Vec<usize>,
+}
+
+/// The state of play after a round
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Round {
+    /// Rounds played, counting from 1
+    pub round: usize,
+    /// The worry level of each item held by each monkey
+    pub items: Vec<Vec<u64>>,
+    /// How many items each monkey has inspected so far
+    pub n_inspections: Vec<usize>,
+}
+
+impl<F: Fn(u64) -> u64> KeepAway<F> {
+    /// A game of `rounds` rounds, in which `relief` is applied to worry after each inspection
+    pub fn new(monkeys: Vec<Monkey>, rounds: usize, relief: F) -> Self {
+        let n_inspections = vec![0; monkeys.len()];
+        KeepAway {
+            monkeys,
+            relief,
+            round: 0,
+            rounds,
+            n_inspections,
+        }
+    }
+
+    /// The product of the two highest inspection counts so far
+    pub fn monkey_business(&self) -> usize {
+        let mut n_inspections = self.n_inspections.clone();
+        n_inspections.sort_unstable_by_key(|n| Reverse(*n));
+        n_inspections.iter().take(2).product()
+    }
+}
+
+impl<F: Fn(u64) -> u64> Simulation for KeepAway<F> {
+    type Snapshot = Round;
+    type Error = Error;
+
+    fn step(&mut self) -> Result<(), Self::Error> {
+        if self.is_done() {
+            return Ok(());
+        }
+        let monkeys = &self.monkeys;
+        for (from_monkey_idx, monkey) in monkeys.iter().enumerate() {
+            // Take the items before throwing any, as a monkey may throw to itself
+            let items = monkey.items.take();
+            self.n_inspections[from_monkey_idx] += items.len();
+
+            for worry in items {
+                let inspected = monkey.operation.apply(worry).ok_or(Error::WorryOverflow)?;
+                let worry = (self.relief)(inspected);
+                let to_partner_idx = worry.is_multiple_of(monkey.divisor) as usize;
+                let to_monkey_idx = monkey.partners[to_partner_idx] as usize;
+                monkeys[to_monkey_idx].items.borrow_mut().push(worry);
+            }
+        }
+        self.round += 1;
+        Ok(())
+    }
+
+    fn is_done(&self) -> bool {
+        self.round == self.rounds
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        Round {
+            round: self.round,
+            items: self
+                .monkeys
+                .iter()
+                .map(|m| m.items.borrow().clone())
+                .collect(),
+            n_inspections: self.n_inspections.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Monkey {
+    pub id: u64,
+    pub divisor: u64,
+    pub operation: Operation,
+    pub items: RefCell<Vec<u64>>,
+    pub partners: [u64; 2], // index 0 -> false, index 1 -> true
+}
+
+/// How a monkey changes my worry level when it inspects an item
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Add(Operand),
+    Mul(Operand),
+}
+
+/// Right-hand side of an operation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operand {
+    Old,
+    Value(u64),
+}
+
+impl Operation {
+    /// The new worry level, or `None` if it overflows
+    pub fn apply(&self, old: u64) -> Option<u64> {
+        let (op, rhs): (fn(u64, u64) -> Option<u64>, _) = match self {
+            Operation::Add(rhs) => (u64::checked_add, rhs),
+            Operation::Mul(rhs) => (u64::checked_mul, rhs),
+        };
+        match rhs {
+            Operand::Old => op(old, old),
+            Operand::Value(n) => op(old, *n),
+        }
+    }
+}
+
+/// A monkey description that doesn't match the puzzle's format, failing at byte `offset`
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseMonkeyError {
+    pub offset: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseMonkeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        finish(s, monkey).map_err(|offset| ParseMonkeyError { offset })
+    }
+}
+
+impl fmt::Display for Monkey {
+    /// Describe the monkey in the same six lines it is parsed from
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let items = self.items.borrow().iter().join(", ");
+        writeln!(f, "Monkey {}:", self.id)?;
+        writeln!(f, "  Starting items: {}", items)?;
+        writeln!(f, "  Operation: new = old {}", self.operation)?;
+        writeln!(f, "  Test: divisible by {}", self.divisor)?;
+        writeln!(f, "    If true: throw to monkey {}", self.partners[1])?;
+        write!(f, "    If false: throw to monkey {}", self.partners[0])
+    }
+}
+
+impl fmt::Display for Operation {
+    /// The right-hand side of `new = old <op> <operand>`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operation::Add(rhs) => write!(f, "+ {}", rhs),
+            Operation::Mul(rhs) => write!(f, "* {}", rhs),
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Old => write!(f, "old"),
+            Operand::Value(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+/// Parse a monkey description of the form
+///
+/// ```text
+/// Monkey 0:
+///   Starting items: 79, 98
+///   Operation: new = old * 19
+///   Test: divisible by 23
+///     If true: throw to monkey 2
+///     If false: throw to monkey 3
+/// ```
+fn monkey(input: &str) -> IResult<&str, Monkey> {
+    let id = delimited(tag("Monkey "), unsigned, char(':'));
+    let items = labelled("Starting items", comma_list(unsigned));
+    let operation = labelled("Operation", preceded(tag("new = old "), operation));
+    let divisor = labelled(
+        "Test",
+        preceded(tag("divisible by "), verify(unsigned, |&d| d != 0)),
+    );
+    let if_true = labelled("If true", preceded(tag("throw to monkey "), unsigned));
+    let if_false = labelled("If false", preceded(tag("throw to monkey "), unsigned));
+
+    // Once a description is started, any error within it can't be recovered by backtracking
+    let description = tuple((
+        line(items),
+        line(operation),
+        line(divisor),
+        line(if_true),
+        if_false,
+    ));
+    map(
+        pair(line(id), cut(description)),
+        |(id, (items, operation, divisor, if_true, if_false))| Monkey {
+            id,
+            divisor,
+            operation,
+            items: RefCell::new(items),
+            partners: [if_false, if_true],
+        },
+    )(input)
+}
+
+/// Parse the right-hand side of "new = old <op> <operand>"
+fn operation(input: &str) -> IResult<&str, Operation> {
+    let operand = alt((
+        value(Operand::Old, tag("old")),
+        map(unsigned, Operand::Value),
+    ));
+    map(
+        separated_pair(one_of("+*"), char(' '), operand),
+        |(op, rhs)| match op {
+            '+' => Operation::Add(rhs),
+            _ => Operation::Mul(rhs),
+        },
+    )(input)
+}
+
+#[inline]
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+/// Least common multiple, or `None` if it overflows
+#[inline]
+fn lcm(a: u64, b: u64) -> Option<u64> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A monkey description that doesn't match the puzzle's format
+    InvalidMonkey(Location),
+    /// A throw to a monkey that doesn't exist
+    UnknownMonkey(Location),
+    /// A worry level too large to track
+    WorryOverflow,
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidMonkey(_) => "invalid monkey description".to_string(),
+            Error::UnknownMonkey(_) => "throw to unknown monkey".to_string(),
+            Error::WorryOverflow => "worry level overflowed".to_string(),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidMonkey(loc) | Error::UnknownMonkey(loc) => Some(loc),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day11/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day11::part1(&Day11::parse(EXAMPLE).unwrap()), Ok(10605));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day11::part2(&Day11::parse(EXAMPLE).unwrap()),
+            Ok(2713310158)
+        );
+    }
+
+    #[test]
+    fn invalid_operation() {
+        let input = EXAMPLE.replace("new = old + 6", "new = old - 6");
+        let err = Day11::parse(&input).unwrap_err();
+        let line = "  Operation: new = old - 6";
+        assert_eq!(err, Error::InvalidMonkey(Location::new(9, line, 23)));
+    }
+
+    #[test]
+    fn throw_to_self() {
+        let input = EXAMPLE.replace("If true: throw to monkey 2", "If true: throw to monkey 0");
+        let monkeys = Day11::parse(&input).unwrap();
+        assert!(Day11::part1(&monkeys).is_ok());
+        assert!(Day11::part2(&monkeys).is_ok());
+    }
+
+    #[test]
+    fn round_trip() {
+        let monkeys = Day11::parse(EXAMPLE).unwrap();
+        let printed = format!("{}\n", monkeys.iter().join("\n\n"));
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(Day11::parse(&printed).unwrap(), monkeys);
+        for monkey in &monkeys {
+            assert_eq!(monkey.to_string().parse::<Monkey>().as_ref(), Ok(monkey));
+        }
+    }
+}
@@ -0,0 +1,425 @@
+/// Day 12: Hill Climbing Algorithm
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::animation::{Animation, Frame};
+use crate::error::{self, Diagnostic, Location};
+use crate::geometry::{Direction, Point};
+use crate::grid::{Grid, ParseGridError};
+use crate::input::normalize;
+use crate::simulation::Simulation;
+use crate::Solution;
+
+type GridSquare = Rc<RefCell<Node>>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+
+    type Input = Heightmap;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        let mut heights = Grid::parse(input, |c| match c {
+            'S' | 'E' | 'a'..='z' => Some(c as u8),
+            _ => None,
+        })
+        .map_err(|e| match e {
+            ParseGridError::InvalidCell(loc) => Error::InvalidHeight(loc),
+            ParseGridError::RaggedRow(loc) => Error::RaggedRow(loc),
+            ParseGridError::Empty => Error::MissingStart,
+        })?;
+
+        let start = heights
+            .position(|&h| h == b'S')
+            .ok_or(Error::MissingStart)?;
+        let end = heights.position(|&h| h == b'E').ok_or(Error::MissingEnd)?;
+        heights[start] = b'a';
+        heights[end] = b'z';
+
+        Ok(Heightmap {
+            heights,
+            start: Point::from(start),
+            end: Point::from(end),
+        })
+    }
+
+    /// Fewest steps from the start position to the best signal
+    fn part1(heightmap: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        shortest_path(heightmap, |node| node.coordinates == heightmap.start).ok_or(Error::NoPath)
+    }
+
+    /// Fewest steps from any square at elevation 'a' to the best signal
+    fn part2(heightmap: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        shortest_path(heightmap, |node| node.height == b'a').ok_or(Error::NoPath)
+    }
+}
+
+/// Elevations with the start (S) and end (E) markers replaced by 'a' and 'z'
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Heightmap {
+    pub heights: Grid<u8>,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl fmt::Display for Heightmap {
+    /// Write the elevations as in the input, with the start and end marked again
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut marked = self.heights.map(|&h| h as char);
+        for (point, marker) in [(self.start, 'S'), (self.end, 'E')] {
+            if let Some(cell) = point.position().and_then(|p| marked.get_mut(p)) {
+                *cell = marker;
+            }
+        }
+        write!(f, "{}", marked)
+    }
+}
+
+#[derive(Debug)]
+pub struct Node {
+    pub coordinates: Point,
+    pub height: u8,
+    pub distance: usize,
+}
+
+/// Fewest steps from the end position back to the closest node satisfying `is_goal`
+///
+/// I use Dijkstra's Algorithm with an edge cost of 1 for this problem, searching backwards
+/// from the end so that Part 2 can stop at the first 'a' it reaches:
+///
+/// 1. initialize start node to distance 0, and all other nodes to infinity (usize::MAX)
+/// 2. add our start node to a min-heap
+/// 3. pop the next position off the heap in order of smallest distance
+///    (if it's the goal, we're done)
+/// 4. search its neighbors that:
+///   - have a "height" no more than one below our current position, and
+///   - that the algorithm has not already "visited"
+/// 5. if the current position's distance + 1 (the one step that it would take to move to
+///    the neighbor) is less than the neighbors existing distance, update it and add it
+///    into the heap.
+/// 6. Add the current position to the "visited" set.
+/// 7. goto 3.
+pub fn shortest_path(heightmap: &Heightmap, is_goal: impl Fn(&Node) -> bool) -> Option<usize> {
+    let mut search = Search::new(heightmap, is_goal);
+    let Ok(()) = search.run();
+    search.found()
+}
+
+/// The search behind [`shortest_path`], popping one position off the heap per step
+pub struct Search<'a, G> {
+    heightmap: &'a Heightmap,
+    grid: Grid<GridSquare>,
+    heap: BinaryHeap<GridSquare>,
+    visited: HashSet<Point>,
+    is_goal: G,
+    /// The position popped by the latest step
+    current: Option<Point>,
+    /// The distance to the goal, once it has been reached
+    found: Option<usize>,
+}
+
+/// The search's state after a step
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchState {
+    /// The position popped by the latest step
+    pub current: Option<Point>,
+    /// Positions waiting on the heap, in no particular order
+    pub frontier: Vec<Point>,
+    /// How many positions have been searched
+    pub n_visited: usize,
+    /// The distance to the goal, once it has been reached
+    pub found: Option<usize>,
+}
+
+impl<'a, G: Fn(&Node) -> bool> Search<'a, G> {
+    /// A search from the end position back to the closest node satisfying `is_goal`
+    pub fn new(heightmap: &'a Heightmap, is_goal: G) -> Self {
+        let heights = &heightmap.heights;
+        let grid: Grid<GridSquare> = Grid::from_fn(heights.width(), heights.height(), |position| {
+            let coordinates = Point::from(position);
+            let distance = match coordinates == heightmap.end {
+                true => 0,
+                false => usize::MAX,
+            };
+            Rc::new(RefCell::new(Node::new(
+                coordinates,
+                heights[position],
+                distance,
+            )))
+        });
+
+        let mut heap: BinaryHeap<GridSquare> = BinaryHeap::new();
+        if let Some(end) = heightmap.end.position() {
+            heap.push(grid[end].clone());
+        }
+
+        Search {
+            heightmap,
+            grid,
+            heap,
+            visited: HashSet::new(),
+            is_goal,
+            current: None,
+            found: None,
+        }
+    }
+
+    /// The heightmap being searched
+    pub fn heightmap(&self) -> &Heightmap {
+        self.heightmap
+    }
+
+    /// The position popped by the latest step
+    pub fn current(&self) -> Option<Point> {
+        self.current
+    }
+
+    /// Positions waiting on the heap, in no particular order
+    pub fn frontier(&self) -> impl Iterator<Item = Point> + '_ {
+        self.heap.iter().map(|node| node.borrow().coordinates)
+    }
+
+    /// Positions already searched
+    pub fn visited(&self) -> &HashSet<Point> {
+        &self.visited
+    }
+
+    /// The fewest steps to the goal, once the search has reached it
+    pub fn found(&self) -> Option<usize> {
+        self.found
+    }
+}
+
+impl<G: Fn(&Node) -> bool> Simulation for Search<'_, G> {
+    type Snapshot = SearchState;
+    type Error = Infallible;
+
+    fn step(&mut self) -> Result<(), Self::Error> {
+        if self.is_done() {
+            return Ok(());
+        }
+        let Some(position) = self.heap.pop() else {
+            return Ok(());
+        };
+        let coordinates = position.borrow().coordinates;
+        self.current = Some(coordinates);
+        if (self.is_goal)(&position.borrow()) {
+            // Found optimal path to goal position, record distance in number of steps
+            self.found = Some(position.borrow().distance);
+            return Ok(());
+        }
+        for neighbor in neighbors(&position, &self.grid, &self.visited) {
+            let new_distance = position.borrow().distance + 1;
+            let existing_distance = neighbor.borrow().distance;
+            if new_distance < existing_distance {
+                // We found a better route to the neighbor, add it to frontier
+                neighbor.borrow_mut().distance = new_distance;
+                self.heap.push(neighbor.clone());
+            }
+        }
+        self.visited.insert(coordinates);
+        Ok(())
+    }
+
+    fn is_done(&self) -> bool {
+        self.found.is_some() || self.heap.is_empty()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        SearchState {
+            current: self.current,
+            frontier: self.frontier().collect(),
+            n_visited: self.visited.len(),
+            found: self.found,
+        }
+    }
+}
+
+/// Determine a vector of neighbors that have yet to be visited that we can move to.
+fn neighbors(
+    position: &GridSquare,
+    heightmap: &Grid<GridSquare>,
+    visited: &HashSet<Point>,
+) -> Vec<GridSquare> {
+    let coordinates = position.borrow().coordinates;
+    let height = position.borrow().height;
+
+    Direction::ALL
+        .into_iter()
+        .map(|direction| coordinates + direction.vector())
+        .filter(|neighbor| !visited.contains(neighbor))
+        .filter_map(|neighbor| heightmap.get(neighbor.position()?))
+        .filter(|node| node.borrow().height >= height - 1)
+        .map(Rc::clone)
+        .collect()
+}
+
+impl Node {
+    fn new(coordinates: Point, height: u8, distance: usize) -> Self {
+        Node {
+            coordinates,
+            height,
+            distance,
+        }
+    }
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.coordinates == other.coordinates
+    }
+}
+
+impl Eq for Node {}
+
+// Reversed ordering so that we have a min-heap
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.cmp(&self.distance)
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Unsearched squares are drawn as their elevation and searched squares as `.`, with positions
+/// waiting on the heap as `+`, the latest position as `@`, and the start and end as `S` and `E`
+impl<G: Fn(&Node) -> bool> Animation for Search<'_, G> {
+    fn focus(&self) -> Point {
+        self.current.unwrap_or(self.heightmap.end)
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let heights = &self.heightmap.heights;
+        frame.fill(
+            |p| match p.position().and_then(|position| heights.get(position)) {
+                Some(_) if self.visited.contains(&p) => '.',
+                Some(&height) => height as char,
+                None => ' ',
+            },
+        );
+        for p in self.frontier() {
+            frame.draw(p, '+');
+        }
+        frame.draw(self.heightmap.start, 'S');
+        frame.draw(self.heightmap.end, 'E');
+        if let Some(current) = self.current {
+            frame.draw(current, '@');
+        }
+    }
+
+    fn status(&self) -> String {
+        match self.found {
+            Some(distance) => format!("found the goal {} steps from the end", distance),
+            None => format!(
+                "{} positions searched, {} on the heap",
+                self.visited.len(),
+                self.heap.len()
+            ),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A square that isn't an elevation in a-z, or the S and E markers
+    InvalidHeight(Location),
+    /// A row whose length differs from the first row's
+    RaggedRow(Location),
+    /// Input without a start (S) marker
+    MissingStart,
+    /// Input without an end (E) marker
+    MissingEnd,
+    /// No path reaches the best signal
+    NoPath,
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidHeight(_) => "expected a-z, S or E".to_string(),
+            Error::RaggedRow(_) => "expected the same number of squares in every row".to_string(),
+            Error::MissingStart => "input should contain a start position (S)".to_string(),
+            Error::MissingEnd => "input should contain an end position (E)".to_string(),
+            Error::NoPath => "no path reaches the best signal".to_string(),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidHeight(loc) | Error::RaggedRow(loc) => Some(loc),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day12/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day12::part1(&Day12::parse(EXAMPLE).unwrap()), Ok(31));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day12::part2(&Day12::parse(EXAMPLE).unwrap()), Ok(29));
+    }
+
+    #[test]
+    fn invalid_height() {
+        let err = Day12::parse("Sabq\nab#r\nacEs\n").unwrap_err();
+        assert_eq!(err, Error::InvalidHeight(Location::new(1, "ab#r", 2)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let heightmap = Day12::parse(EXAMPLE).unwrap();
+        let printed = format!("{}\n", heightmap);
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(Day12::parse(&printed).unwrap(), heightmap);
+    }
+
+    #[test]
+    fn search_frontier() {
+        let heightmap = Day12::parse(EXAMPLE).unwrap();
+        let mut search = Search::new(&heightmap, |node| node.coordinates == heightmap.start);
+        let mut states = vec![];
+        search
+            .run_observed(|state| states.push(state.clone()))
+            .unwrap();
+
+        // The search starts from the end, whose only neighbour within reach is the 'z' to its left
+        assert_eq!(states[0].current, Some(heightmap.end));
+        assert_eq!(states[0].frontier, vec![heightmap.end + Point::new(-1, 0)]);
+        let last = states.last().unwrap();
+        assert_eq!(last.current, Some(heightmap.start));
+        assert_eq!(last.found, Some(31));
+        assert_eq!(last.n_visited, search.visited().len());
+    }
+}
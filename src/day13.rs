@@ -0,0 +1,250 @@
+/// Day 13: Distress Signal
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    character::complete::char,
+    combinator::{cut, map},
+    multi::separated_list0,
+    sequence::delimited,
+    IResult,
+};
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::error::{self, Diagnostic, Location};
+use crate::input::normalize;
+use crate::parse::{finish, unsigned};
+use crate::Solution;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Distress Signal";
+
+    type Input = Vec<(Packet, Packet)>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Error;
+
+    /// Parse pairs of packets on consecutive lines, each pair separated by a blank line
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        let mut pairs = vec![];
+        // The first packet of the current pair, with the line it's on
+        let mut left = None;
+        // Whether the last line finished a pair, so must be followed by a blank line
+        let mut paired = false;
+        let unpaired = |(_, i, line)| Error::UnpairedPacket(Location::new(i, line, 0));
+
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                if let Some(left) = left {
+                    return Err(unpaired(left));
+                }
+                paired = false;
+                continue;
+            }
+            if paired {
+                return Err(Error::MissingBlankLine(Location::new(i, line, 0)));
+            }
+            let packet = finish(line, parse_list)
+                .map_err(|offset| Error::InvalidPacket(Location::new(i, line, offset)))?;
+            match left.take() {
+                None => left = Some((packet, i, line)),
+                Some((left, _, _)) => {
+                    pairs.push((left, packet));
+                    paired = true;
+                }
+            }
+        }
+        if let Some(left) = left {
+            return Err(unpaired(left));
+        }
+
+        Ok(pairs)
+    }
+
+    /// Sum of the indices of pairs that are in the right order
+    fn part1(pairs: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        let mut sum = 0;
+
+        for (i, (left, right)) in pairs.iter().enumerate() {
+            let index = i + 1;
+            if left < right {
+                sum += index;
+            }
+        }
+
+        Ok(sum)
+    }
+
+    /// Product of the indices of the divider packets once all packets are sorted
+    fn part2(pairs: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        use Packet::*;
+
+        // Divider packet [[2]]
+        let divider1 = List(vec![List(vec![Int(2)])]);
+        // Divider packet [[6]]
+        let divider2 = List(vec![List(vec![Int(6)])]);
+
+        let mut packets: Vec<Packet> = pairs
+            .iter()
+            .flat_map(|(left, right)| [left.clone(), right.clone()])
+            .collect();
+
+        packets.push(divider1.clone());
+        packets.push(divider2.clone());
+
+        packets.sort();
+
+        Ok(packets
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, pkt)| {
+                if pkt == divider1 || pkt == divider2 {
+                    Some(i + 1)
+                } else {
+                    None
+                }
+            })
+            .product())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Packet {
+    Int(i32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Packet::*;
+        match (self, other) {
+            (Int(x), Int(y)) => x.cmp(y),
+            (List(u), List(v)) => u.cmp(v),
+            (Int(x), List(v)) => vec![Int(*x)].cmp(v),
+            (List(u), Int(y)) => u.cmp(&vec![Int(*y)]),
+        }
+    }
+}
+
+impl fmt::Display for Packet {
+    /// Write the packet as it appears in the input, without spaces
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Packet::Int(x) => write!(f, "{}", x),
+            Packet::List(packets) => write!(f, "[{}]", packets.iter().join(",")),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Parse an integer
+fn parse_integer(input: &str) -> IResult<&str, Packet> {
+    map(unsigned, Packet::Int)(input)
+}
+
+// Parse a list of Packets
+fn parse_list(input: &str) -> IResult<&str, Packet> {
+    let parser = separated_list0(char(','), parse_packet);
+
+    // Once a list is opened, any error within it can't be recovered by backtracking
+    delimited(char('['), cut(parser), cut(char(']')))(input)
+        .map(|(remaining, pkts)| (remaining, Packet::List(pkts)))
+}
+
+// Parse a Packet (either an int or another list)
+fn parse_packet(input: &str) -> IResult<&str, Packet> {
+    alt((parse_integer, parse_list))(input)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't a list of integers and nested lists
+    InvalidPacket(Location),
+    /// A packet without a partner on the following line
+    UnpairedPacket(Location),
+    /// A pair of packets followed by another packet rather than a blank line
+    MissingBlankLine(Location),
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidPacket(_) => "invalid packet".to_string(),
+            Error::UnpairedPacket(_) => "expected a second packet".to_string(),
+            Error::MissingBlankLine(_) => "expected a blank line between pairs".to_string(),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidPacket(loc)
+            | Error::UnpairedPacket(loc)
+            | Error::MissingBlankLine(loc) => Some(loc),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day13/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day13::part1(&Day13::parse(EXAMPLE).unwrap()), Ok(13));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day13::part2(&Day13::parse(EXAMPLE).unwrap()), Ok(140));
+    }
+
+    #[test]
+    fn invalid_packet() {
+        let err = Day13::parse("[1,1,3]\n[1,[2,x]]\n").unwrap_err();
+        assert_eq!(err, Error::InvalidPacket(Location::new(1, "[1,[2,x]]", 5)));
+    }
+
+    #[test]
+    fn unpaired_packet() {
+        let err = Day13::parse("[1]\n[2]\n\n[3]\n").unwrap_err();
+        assert_eq!(err, Error::UnpairedPacket(Location::new(3, "[3]", 0)));
+        let err = Day13::parse("[1]\n\n[2]\n[3]\n").unwrap_err();
+        assert_eq!(err, Error::UnpairedPacket(Location::new(0, "[1]", 0)));
+    }
+
+    #[test]
+    fn missing_blank_line() {
+        let err = Day13::parse("[1]\n[2]\n[3]\n[4]\n").unwrap_err();
+        assert_eq!(err, Error::MissingBlankLine(Location::new(2, "[3]", 0)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let pairs = Day13::parse(EXAMPLE).unwrap();
+        let printed = pairs
+            .iter()
+            .map(|(left, right)| format!("{}\n{}\n", left, right))
+            .join("\n");
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(Day13::parse(&printed).unwrap(), pairs);
+    }
+}
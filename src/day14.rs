@@ -0,0 +1,388 @@
+/// Day 14: Regolith Reservoir
+use std::convert::Infallible;
+use std::fmt;
+
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag, character::complete::char, combinator::map, multi::separated_list1,
+    sequence::separated_pair,
+};
+
+use crate::animation::{Animation, Frame};
+use crate::error::{self, Diagnostic, Location};
+use crate::geometry::{Point, Vector};
+use crate::grid::Grid;
+use crate::input::normalize;
+use crate::parse::{finish, unsigned};
+use crate::simulation::Simulation;
+use crate::Solution;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Regolith Reservoir";
+
+    type Input = Cave;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        let point = map(separated_pair(unsigned, char(','), unsigned), |(x, y)| {
+            Point::new(x, y)
+        });
+        let mut path = separated_list1(tag(" -> "), point);
+
+        let paths = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let path = finish(line, &mut path)
+                    .map_err(|offset| Error::InvalidPath(Location::new(i, line, offset)))?;
+                if path
+                    .iter()
+                    .tuple_windows()
+                    .any(|(a, b)| a.x != b.x && a.y != b.y)
+                {
+                    return Err(Error::DiagonalPath(Location::new(i, line, 0)));
+                }
+                let too_deep = line.split(" -> ").zip(&path).find(|(_, p)| p.y > MAX_DEPTH);
+                if let Some((point, _)) = too_deep {
+                    let y = point.split_once(',').map_or(point, |(_, y)| y);
+                    return Err(Error::TooDeep(Location::of(i, line, y)));
+                }
+                Ok(path)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Cave { paths })
+    }
+
+    /// Sand at rest before it starts falling into the abyss
+    fn part1(cave: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        let mut sand = Sand::new(cave, false);
+        let Ok(()) = sand.run();
+        Ok(sand.n_resting())
+    }
+
+    /// Sand at rest on the floor once it blocks the source
+    fn part2(cave: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        let mut sand = Sand::new(cave, true);
+        let Ok(()) = sand.run();
+        Ok(sand.n_resting())
+    }
+}
+
+/// Where sand pours into the cave
+pub const SOURCE: Point = Point::new(500, 0);
+
+/// The deepest rock a cave may have
+///
+/// Sand can reach a triangle of about twice the depth squared squares below the source, which are
+/// all kept in a grid, so this keeps that grid to a few hundred megabytes.
+pub const MAX_DEPTH: i32 = 10_000;
+
+/// The scanned cave: paths of solid rock, each a series of horizontal and vertical lines
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cave {
+    pub paths: Vec<Vec<Point>>,
+}
+
+impl Cave {
+    /// Every position of solid rock
+    pub fn rocks(&self) -> impl Iterator<Item = Point> + '_ {
+        self.paths.iter().flat_map(|path| {
+            let lines = path.iter().tuple_windows().flat_map(|(&from, &to)| {
+                let step = (to - from).signum();
+                (1..=from.manhattan_distance(to) as i32).map(move |i| from + step * i)
+            });
+            path.first().copied().into_iter().chain(lines)
+        })
+    }
+
+    /// The y coordinate of the lowest rock
+    pub fn depth(&self) -> i32 {
+        self.paths.iter().flatten().map(|p| p.y).max().unwrap_or(0)
+    }
+}
+
+impl fmt::Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for path in &self.paths {
+            let points = path.iter().map(|p| format!("{},{}", p.x, p.y));
+            writeln!(f, "{}", points.format(" -> "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Sand pouring into a cave from [`SOURCE`], one square of a grain's fall per step
+///
+/// Without a floor, the simulation is done once a grain falls past the lowest rock into the
+/// abyss; with one, it is done once sand comes to rest at the source.
+#[derive(Clone, Debug)]
+pub struct Sand {
+    /// Rock and sand at rest, covering every square sand can reach
+    squares: Grid<Square>,
+    /// The x coordinate of the grid's first column
+    left: i32,
+    /// The y coordinate of the lowest rock
+    depth: i32,
+    floor: bool,
+    grain: Point,
+    at_rest: bool,
+    n_resting: usize,
+    done: bool,
+}
+
+/// What fills a square of the cave
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Square {
+    Air,
+    Rock,
+    Sand,
+}
+
+/// The sand's state after a step
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SandState {
+    /// The position of the latest grain
+    pub grain: Point,
+    /// Whether the latest grain has come to rest, rather than still falling
+    pub at_rest: bool,
+    /// How many grains are at rest, including the latest
+    pub n_resting: usize,
+}
+
+impl Sand {
+    /// Sand about to pour into `cave`, which has a floor two below its lowest rock if `floor`
+    pub fn new(cave: &Cave, floor: bool) -> Self {
+        let depth = cave.depth();
+        // Sand spreads at most one square sideways for each square it falls
+        let reach = depth + 2;
+        let left = SOURCE.x - reach;
+        let mut squares = Grid::new(2 * reach as usize + 1, depth as usize + 2, Square::Air);
+        for rock in cave.rocks() {
+            if let Some(square) = squares.get_mut(grid_position(rock, left)) {
+                *square = Square::Rock;
+            }
+        }
+
+        Sand {
+            squares,
+            left,
+            depth,
+            floor,
+            grain: SOURCE,
+            at_rest: false,
+            n_resting: 0,
+            done: false,
+        }
+    }
+
+    /// How many grains have come to rest
+    pub fn n_resting(&self) -> usize {
+        self.n_resting
+    }
+
+    /// The latest grain's position
+    pub fn grain(&self) -> Point {
+        self.grain
+    }
+
+    /// What fills the square at `p`, including the floor if there is one
+    pub fn square(&self, p: Point) -> Square {
+        match self.squares.get(grid_position(p, self.left)) {
+            Some(&square) => square,
+            None if self.floor && p.y == self.depth + 2 => Square::Rock,
+            None => Square::Air,
+        }
+    }
+}
+
+/// The grid position of `p` given the x coordinate of the grid's first column
+fn grid_position(p: Point, left: i32) -> (usize, usize) {
+    let col = usize::try_from(p.x - left).unwrap_or(usize::MAX);
+    (p.y as usize, col)
+}
+
+impl Simulation for Sand {
+    type Snapshot = SandState;
+    type Error = Infallible;
+
+    fn step(&mut self) -> Result<(), Self::Error> {
+        if self.done {
+            return Ok(());
+        }
+        let from = match self.at_rest {
+            true => SOURCE,
+            false => self.grain,
+        };
+        // Straight down if possible, otherwise down and to the left, otherwise down and right
+        let fall = [Vector::new(0, 1), Vector::new(-1, 1), Vector::new(1, 1)]
+            .map(|v| from + v)
+            .into_iter()
+            .find(|&to| self.square(to) == Square::Air);
+
+        match fall {
+            Some(to) => {
+                self.grain = to;
+                self.at_rest = false;
+                self.done = !self.floor && to.y > self.depth;
+            }
+            None => {
+                self.squares[grid_position(from, self.left)] = Square::Sand;
+                self.grain = from;
+                self.at_rest = true;
+                self.n_resting += 1;
+                self.done = from == SOURCE;
+            }
+        }
+        Ok(())
+    }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        SandState {
+            grain: self.grain,
+            at_rest: self.at_rest,
+            n_resting: self.n_resting,
+        }
+    }
+}
+
+/// Drawn as in the puzzle: rock `#`, sand `o` and air `.`, with the source `+`
+impl Animation for Sand {
+    fn focus(&self) -> Point {
+        self.grain
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        frame.fill(|p| match self.square(p) {
+            Square::Air => '.',
+            Square::Rock => '#',
+            Square::Sand => 'o',
+        });
+        frame.draw(SOURCE, '+');
+        frame.draw(self.grain, 'o');
+    }
+
+    fn status(&self) -> String {
+        format!("{} units of sand at rest", self.n_resting)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't a list of `x,y` points separated by ` -> `
+    InvalidPath(Location),
+    /// A path with a line that is neither horizontal nor vertical
+    DiagonalPath(Location),
+    /// Rock deeper than [`MAX_DEPTH`]
+    TooDeep(Location),
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidPath(_) => "expected `x,y -> x,y ...`".to_string(),
+            Error::DiagonalPath(_) => "diagonal rock path".to_string(),
+            Error::TooDeep(_) => format!("rock deeper than {}", MAX_DEPTH),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidPath(loc) | Error::DiagonalPath(loc) | Error::TooDeep(loc) => Some(loc),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day14/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day14::part1(&Day14::parse(EXAMPLE).unwrap()), Ok(24));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day14::part2(&Day14::parse(EXAMPLE).unwrap()), Ok(93));
+    }
+
+    #[test]
+    fn diagonal_path() {
+        let err = Day14::parse("498,4 -> 498,6\n503,4 -> 502,5\n").unwrap_err();
+        assert_eq!(
+            err,
+            Error::DiagonalPath(Location::new(1, "503,4 -> 502,5", 0))
+        );
+    }
+
+    #[test]
+    fn too_deep() {
+        let line = "498,4 -> 498,2000000000 -> 496,2000000000";
+        let err = Day14::parse(line).unwrap_err();
+        assert_eq!(err, Error::TooDeep(Location::new(0, line, 13)));
+        assert!(Day14::parse("498,4 -> 498,10000").is_ok());
+    }
+
+    #[test]
+    fn round_trip() {
+        let cave = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(cave.to_string(), normalize(EXAMPLE));
+        assert_eq!(cave.rocks().count(), 20);
+    }
+
+    #[test]
+    fn first_grain() {
+        let mut sand = Sand::new(&Day14::parse(EXAMPLE).unwrap(), false);
+        let mut states = vec![];
+        sand.run_observed(|&state| states.push(state)).unwrap();
+        let first_rest = states.iter().find(|state| state.at_rest).unwrap();
+        assert_eq!(first_rest.grain, Point::new(500, 8));
+        assert_eq!(states.last().unwrap().n_resting, 24);
+    }
+
+    #[test]
+    fn draw() {
+        let cave = Day14::parse(EXAMPLE).unwrap();
+        let mut sand = Sand::new(&cave, false);
+        while sand.n_resting() < 5 {
+            sand.step().unwrap();
+        }
+        let mut frame = Frame::new(10, 10);
+        frame.center(Point::new(499, 5));
+        sand.draw(&mut frame);
+        let expected = [
+            "......+...",
+            "..........",
+            "..........",
+            "..........",
+            "....#...##",
+            "....#...#.",
+            "..###...#.",
+            "......o.#.",
+            "....oooo#.",
+            "#########.",
+        ];
+        assert_eq!(frame.to_string(), expected.join("\n"));
+    }
+}
@@ -0,0 +1,429 @@
+/// Day 15: Beacon Exclusion Zone
+use std::collections::BTreeSet;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::tag,
+    sequence::{pair, preceded},
+};
+
+use crate::error::{self, Diagnostic, Location};
+use crate::geometry::Point;
+use crate::input::normalize;
+use crate::parse::{coordinates, finish};
+use crate::Solution;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Beacon Exclusion Zone";
+
+    type Input = Vec<Telemetry>;
+    type Part1 = i32;
+    type Part2 = isize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                Telemetry::from_str(l).map_err(|e| match e {
+                    ParseTelemetryError::Syntax { offset } => {
+                        Error::InvalidTelemetry(Location::new(i, l, offset))
+                    }
+                    ParseTelemetryError::OutOfRange { offset } => {
+                        Error::BeaconOutOfRange(Location::new(i, l, offset))
+                    }
+                })
+            })
+            .collect()
+    }
+
+    fn part1(telemetry: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        Ok(covered_positions(telemetry, 2_000_000))
+    }
+
+    fn part2(telemetry: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        tuning_frequency(telemetry, 4_000_000).ok_or(Error::NotFound)
+    }
+}
+
+// Algorithm:
+//
+// 1. Scan through input and determine (min, max) value of X
+// 2. For y=target_y
+// 3.   Find the ranges of X covered by the sensors
+// 4.   Subtract the total covered range from (max_x - min_x)
+//
+// NOTE: The number of sensors is very small, so there is no benefit to merging the ranges
+//       covered.
+pub fn covered_positions(telemetry: &[Telemetry], target_y: i32) -> i32 {
+    // Find the range of x covered by each sensor at row `target_y`
+    let mut min_x = i32::MAX;
+    let mut max_x = i32::MIN;
+    let mut ranges: Vec<RangeInclusive<i32>> = vec![];
+    let mut target_line_beacons: BTreeSet<i32> = BTreeSet::new();
+
+    for pkt in telemetry.iter() {
+        let ydist = pkt.sensor.y.abs_diff(target_y);
+        if ydist > pkt.distance {
+            continue; // this sensor covers none of the target row
+        }
+        // Fits, as the sensor's reach does when parsed
+        let xdist = (pkt.distance - ydist) as i32;
+        let x_low = pkt.sensor.x - xdist;
+        let x_high = pkt.sensor.x + xdist;
+        min_x = min_x.min(x_low);
+        max_x = max_x.max(x_high);
+        ranges.push(RangeInclusive::new(x_low, x_high));
+
+        // Beacons may not be unique, so use BST to ensure uniqueness
+        if pkt.beacon.y == target_y {
+            target_line_beacons.insert(pkt.beacon.x);
+        }
+    }
+
+    // Count locations on the target line covered by sensors
+    let mut count = 0;
+    for i in min_x..=max_x {
+        let covered = ranges.iter().any(|r| r.contains(&i));
+        let beacon = target_line_beacons.contains(&i);
+        if covered && !beacon {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+// Algorithm:
+//
+// The search space 4000000 * 4000000 is far too large to brute force, but we can
+// reduce the search space by realizing that if there is exactly one point in [0, max_coordinate]
+// that is not covered by any sensor, than this point must necessarily be on the boundary
+// of multiple sensors.
+//
+// 1. Create an iterator over the "boundary" of a sensor, i.e., all the points 1 step
+//    farther than the distance to a sensor's closest beacon.
+// 2. For each boundary point of each sensor, determine whether another sensor would have detected
+//    a beacon there by calulating the distance between that point and each other sensor.
+// 3. If any boundary point is not covered by any other sensor, it must be the distress beacon.
+pub fn tuning_frequency(telemetry: &[Telemetry], max_coordinate: i32) -> Option<isize> {
+    let in_bounds =
+        |p: &Point| p.x >= 0 && p.y >= 0 && p.x <= max_coordinate && p.y <= max_coordinate;
+    for (i, this) in telemetry.iter().enumerate() {
+        let boundary = SensorBoundary::new(this.sensor, this.distance);
+        for point in boundary {
+            if !in_bounds(&point) {
+                continue;
+            }
+            let mut covered = false;
+            let other_sensors =
+                telemetry
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(j, s)| if j != i { Some(s) } else { None });
+            for other in other_sensors {
+                if other.sensor.manhattan_distance(point) <= other.distance {
+                    covered = true;
+                    break;
+                }
+            }
+            if !covered {
+                return Some(point.x as isize * 4000000 + point.y as isize);
+            }
+        }
+    }
+    None
+}
+
+/// Why a line of telemetry couldn't be parsed, at byte `offset`
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseTelemetryError {
+    /// The line doesn't match the puzzle's format
+    Syntax { offset: usize },
+    /// The beacon, at `offset`, is so far from the sensor that the area around the sensor doesn't
+    /// fit in an `i32`
+    OutOfRange { offset: usize },
+}
+
+const BEACON: &str = ": closest beacon is at ";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Telemetry {
+    pub sensor: Point,
+    pub beacon: Point,
+    pub distance: u32,
+}
+
+impl FromStr for Telemetry {
+    type Err = ParseTelemetryError;
+
+    /// Parse a line of the form
+    ///     "Sensor at x=2, y=18: closest beacon is at x=-2, y=15"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parser = pair(
+            preceded(tag("Sensor at "), coordinates),
+            preceded(tag(BEACON), coordinates),
+        );
+        let (sensor, beacon) =
+            finish(s, parser).map_err(|offset| ParseTelemetryError::Syntax { offset })?;
+        let distance = reach(sensor, beacon).ok_or_else(|| ParseTelemetryError::OutOfRange {
+            offset: s.find(BEACON).map_or(0, |i| i + BEACON.len()),
+        })?;
+
+        Ok(Telemetry {
+            sensor,
+            beacon,
+            distance,
+        })
+    }
+}
+
+/// The distance from `sensor` to `beacon`, if every point within it of the sensor, and one step
+/// beyond, has coordinates that fit in an `i32`
+fn reach(sensor: Point, beacon: Point) -> Option<u32> {
+    let distance = sensor
+        .x
+        .abs_diff(beacon.x)
+        .checked_add(sensor.y.abs_diff(beacon.y))?;
+    let boundary = i32::try_from(distance.checked_add(1)?).ok()?;
+    let fits = |c: i32| c.checked_sub(boundary).is_some() && c.checked_add(boundary).is_some();
+    (fits(sensor.x) && fits(sensor.y)).then_some(distance)
+}
+
+impl fmt::Display for Telemetry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Telemetry { sensor, beacon, .. } = self;
+        write!(
+            f,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            sensor.x, sensor.y, beacon.x, beacon.y
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't `Sensor at x=<int>, y=<int>: closest beacon is at x=<int>, y=<int>`
+    InvalidTelemetry(Location),
+    /// A beacon too far from its sensor for the area around the sensor to fit in an `i32`
+    BeaconOutOfRange(Location),
+    /// No position within the search area is out of range of every sensor
+    NotFound,
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidTelemetry(_) => {
+                "expected `Sensor at x=<int>, y=<int>: closest beacon is at x=<int>, y=<int>`"
+                    .to_string()
+            }
+            Error::BeaconOutOfRange(_) => "beacon is too far from its sensor".to_string(),
+            Error::NotFound => "distress beacon not found".to_string(),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidTelemetry(loc) | Error::BeaconOutOfRange(loc) => Some(loc),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// An iterator for points around (just outside) a sensor's boundary
+#[derive(Debug)]
+pub struct SensorBoundary {
+    sensor: Point,
+    distance: u32,
+    current: Option<Point>,
+    size: usize,
+}
+
+impl SensorBoundary {
+    pub fn new(sensor: Point, distance: u32) -> Self {
+        SensorBoundary {
+            sensor,
+            distance,
+            current: None,
+            // Consider distance to left and right (x2), above and below each (x2)
+            // and directly left, top, bottom, and right of center.
+            size: distance as usize * 4 + 4,
+        }
+    }
+}
+
+/// Iterate points around a sensor boundary.
+///
+/// Given a sensor (S) and beacon (B) in the following configuration (distance 1),
+/// the perimeter (P) will move from left to right, heigh to low, as follows:
+///
+/// .....    .....    .....    ..P..    .....    .....    .....    .....
+/// ..#..    .P#..    ..#..    ..#..    ..#..    ..#P.    ..#..    ..#..
+/// P#SB. -> .#SB. -> .#SB. -> .#SB. -> .#SB. -> .#SB. -> .#SB. -> .#SBP
+/// ..#..    ..#..    .P#..    ..#..    ..#..    ..#..    ..#P.    ..#..
+/// .....    .....    .....    .....    ..P..    .....    .....    .....
+///
+impl Iterator for SensorBoundary {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Point {
+            x: sensor_x,
+            y: sensor_y,
+        } = self.sensor;
+        if self.size == 0 {
+            return None;
+        }
+
+        self.size -= 1;
+
+        let distance = self.distance as i32;
+
+        // First perimeter point
+        let Some(Point {
+            x: mut current_x,
+            y: mut current_y,
+        }) = self.current
+        else {
+            let current = Point {
+                x: sensor_x - distance - 1,
+                y: sensor_y,
+            };
+            self.current = Some(current);
+            return Some(current);
+        };
+
+        if current_y < sensor_y {
+            current_y = sensor_y + (distance - sensor_x.abs_diff(current_x) as i32) + 1;
+        } else {
+            current_x += 1;
+            current_y = sensor_y - (distance - sensor_x.abs_diff(current_x) as i32) - 1;
+        }
+
+        self.current = Some(Point {
+            x: current_x,
+            y: current_y,
+        });
+        self.current
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.size, Some(self.size))
+    }
+}
+
+impl ExactSizeIterator for SensorBoundary {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day15/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(covered_positions(&Day15::parse(EXAMPLE).unwrap(), 10), 26);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            tuning_frequency(&Day15::parse(EXAMPLE).unwrap(), 20),
+            Some(56000011)
+        );
+    }
+
+    #[test]
+    fn invalid_telemetry() {
+        let line = "Sensor at x=2, y=18: closest beacon is at x=-2, z=15";
+        let err = Day15::parse(line).unwrap_err();
+        assert_eq!(err, Error::InvalidTelemetry(Location::new(0, line, 48)));
+    }
+
+    /// A crasher from the `telemetry` fuzz target, minimized: the distance to the beacon used to
+    /// overflow
+    #[test]
+    fn beacon_out_of_range() {
+        let line = "Sensor at x=-78, y=7: closest beacon is at x=2147483647, y=2147483647";
+        let err = Day15::parse(line).unwrap_err();
+        assert_eq!(err, Error::BeaconOutOfRange(Location::new(0, line, 43)));
+
+        // The distance fits, but not the area around the sensor
+        let line = "Sensor at x=0, y=0: closest beacon is at x=2147483647, y=0";
+        assert!(matches!(
+            Day15::parse(line),
+            Err(Error::BeaconOutOfRange(_))
+        ));
+        let line = "Sensor at x=0, y=0: closest beacon is at x=2147483646, y=0";
+        assert!(Day15::parse(line).is_ok());
+    }
+
+    #[test]
+    fn sensor_far_from_row() {
+        // The distance from the sensor to the row doesn't fit in an i32
+        let line =
+            "Sensor at x=-1000000, y=-2147000000: closest beacon is at x=-1000000, y=-2146999999";
+        let telemetry = Day15::parse(line).unwrap();
+        assert_eq!(Day15::part1(&telemetry), Ok(0));
+    }
+
+    #[test]
+    fn round_trip() {
+        let telemetry = Day15::parse(EXAMPLE).unwrap();
+        let printed: String = telemetry.iter().map(|t| format!("{}\n", t)).collect();
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(Day15::parse(&printed).unwrap(), telemetry);
+    }
+
+    #[test]
+    fn boundary_values() {
+        let s = "Sensor at x=2, y=2: closest beacon is at x=3, y=2";
+        let t = Telemetry::from_str(s).unwrap();
+        let b = SensorBoundary::new(t.sensor, t.distance);
+        let actual: Vec<Point> = b.collect();
+        let expected = vec![
+            Point { x: 0, y: 2 },
+            Point { x: 1, y: 1 },
+            Point { x: 1, y: 3 },
+            Point { x: 2, y: 0 },
+            Point { x: 2, y: 4 },
+            Point { x: 3, y: 1 },
+            Point { x: 3, y: 3 },
+            Point { x: 4, y: 2 },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn boundary_len() {
+        let s = "Sensor at x=2, y=2: closest beacon is at x=3, y=2";
+        let t = Telemetry::from_str(s).unwrap();
+        let mut b = SensorBoundary::new(t.sensor, t.distance);
+        assert_eq!(b.len(), 8);
+        _ = b.next();
+        assert_eq!(b.len(), 7);
+        for _ in 0..6 {
+            _ = b.next();
+        }
+        assert_eq!(b.len(), 1);
+        assert!(b.next().is_some());
+        assert!(b.next().is_none());
+        assert_eq!(b.len(), 0);
+    }
+}
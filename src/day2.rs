@@ -0,0 +1,160 @@
+/// Day 2: Rock Paper Scissors
+use std::fmt;
+
+use crate::error::{self, Diagnostic, Location};
+use crate::input::normalize;
+use crate::Solution;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    type Input = Vec<Round>;
+    type Part1 = i32;
+    type Part2 = i32;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                Round::new(line)
+                    .map_err(|offset| Error::InvalidRound(Location::new(i, line, offset)))
+            })
+            .collect()
+    }
+
+    /// Score the strategy guide, reading the second column as our shape
+    fn part1(rounds: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        let score = rounds
+            .iter()
+            .map(|round| match (round.opponent, round.response) {
+                ('A', 'X') => 4, // 1 + 3
+                ('A', 'Y') => 8, // 2 + 6
+                ('A', 'Z') => 3, // 3 + 0
+                ('B', 'X') => 1, // 1 + 0
+                ('B', 'Y') => 5, // 2 + 3
+                ('B', 'Z') => 9, // 3 + 6
+                ('C', 'X') => 7, // 1 + 6
+                ('C', 'Y') => 2, // 2 + 0
+                ('C', 'Z') => 6, // 3 + 3
+                _ => unreachable!("rounds are validated when parsed"),
+            })
+            .sum();
+        Ok(score)
+    }
+
+    /// Score the strategy guide, reading the second column as the desired outcome
+    fn part2(rounds: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        let score = rounds
+            .iter()
+            .map(|round| match (round.opponent, round.response) {
+                ('A', 'X') => 3, // 3 + 0
+                ('A', 'Y') => 4, // 1 + 3
+                ('A', 'Z') => 8, // 2 + 6
+                ('B', 'X') => 1, // 1 + 0
+                ('B', 'Y') => 5, // 2 + 3
+                ('B', 'Z') => 9, // 3 + 6
+                ('C', 'X') => 2, // 2 + 0
+                ('C', 'Y') => 6, // 3 + 3
+                ('C', 'Z') => 7, // 1 + 6
+                _ => unreachable!("rounds are validated when parsed"),
+            })
+            .sum();
+        Ok(score)
+    }
+}
+
+/// One line of the strategy guide
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Round {
+    /// Opponent's shape, 'A', 'B' or 'C'
+    pub opponent: char,
+    /// Our response, 'X', 'Y' or 'Z'
+    pub response: char,
+}
+
+impl Round {
+    /// Parse a line of the form "A Y", returning the offset of the first unexpected byte on error
+    fn new(line: &str) -> Result<Self, usize> {
+        match *line.as_bytes() {
+            [opponent @ b'A'..=b'C', b' ', response @ b'X'..=b'Z'] => Ok(Round {
+                opponent: opponent as char,
+                response: response as char,
+            }),
+            [b'A'..=b'C', b' ', b'X'..=b'Z', ..] => Err(3),
+            [b'A'..=b'C', b' ', ..] => Err(2),
+            [b'A'..=b'C', ..] => Err(1),
+            _ => Err(0),
+        }
+    }
+}
+
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.opponent, self.response)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't an opponent's shape and our response, e.g. "A Y"
+    InvalidRound(Location),
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidRound(_) => "expected `A|B|C X|Y|Z`".to_string(),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidRound(loc) => Some(loc),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day2/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day2::part1(&Day2::parse(EXAMPLE).unwrap()), Ok(15));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day2::part2(&Day2::parse(EXAMPLE).unwrap()), Ok(12));
+    }
+
+    #[test]
+    fn invalid_round() {
+        let err = Day2::parse("A Y\nB W\n").unwrap_err();
+        assert_eq!(err, Error::InvalidRound(Location::new(1, "B W", 2)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let rounds = Day2::parse(EXAMPLE).unwrap();
+        let printed: String = rounds.iter().map(|round| format!("{}\n", round)).collect();
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(Day2::parse(&printed).unwrap(), rounds);
+    }
+}
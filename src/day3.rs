@@ -0,0 +1,200 @@
+/// Day 3: Rucksack Reorganization
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::error::{self, Diagnostic, Location};
+use crate::input::normalize;
+use crate::Solution;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Input = Vec<Rucksack>;
+    type Part1 = i32;
+    type Part2 = i32;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                if line.len() % 2 != 0 {
+                    return Err(Error::UnevenCompartments(Location::new(i, line, 0)));
+                }
+                let items = line
+                    .bytes()
+                    .enumerate()
+                    .map(|(j, c)| {
+                        calculate_priority(&c)
+                            .ok_or_else(|| Error::InvalidItem(Location::new(i, line, j)))
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok(Rucksack { line: i, items })
+            })
+            .collect()
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        rucksacks
+            .iter()
+            .map(|rucksack| {
+                let boundary = rucksack.items.len() / 2;
+                let compartment_1: HashSet<i32> =
+                    HashSet::from_iter(rucksack.items[..boundary].iter().cloned());
+                let compartment_2: HashSet<i32> =
+                    HashSet::from_iter(rucksack.items[boundary..].iter().cloned());
+                // First and second half of line should contain 1 duplicate item
+                compartment_1
+                    .intersection(&compartment_2)
+                    .next()
+                    .copied()
+                    .ok_or(Error::NoDuplicate {
+                        line: rucksack.line + 1,
+                    })
+            })
+            .sum()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        let mut lines = rucksacks.iter();
+        let mut priority_sum: i32 = 0;
+        // NOTE: Iterator.array_chunks could make this more functional but it's currently in nightly
+        while let Some(line1) = lines.next() {
+            // Number of lines should be a multiple of 3
+            let incomplete = Error::IncompleteGroup {
+                line: line1.line + 1,
+            };
+            let line2 = lines.next().ok_or(incomplete.clone())?;
+            let line3 = lines.next().ok_or(incomplete)?;
+            let elf1: HashSet<i32> = HashSet::from_iter(line1.items.iter().cloned());
+            let elf2: HashSet<i32> = HashSet::from_iter(line2.items.iter().cloned());
+            let elf3: HashSet<i32> = HashSet::from_iter(line3.items.iter().cloned());
+            let elf1_and_elf2: HashSet<i32> = elf1.intersection(&elf2).cloned().collect();
+            // Group of 3 lines should have exactly 1 item in common
+            let badge = elf1_and_elf2
+                .intersection(&elf3)
+                .next()
+                .ok_or(Error::NoBadge {
+                    line: line1.line + 1,
+                })?;
+            priority_sum += badge;
+        }
+        Ok(priority_sum)
+    }
+}
+
+/// A rucksack's items, as priorities
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    /// Line of the input listing this rucksack, counting from 0
+    pub line: usize,
+    pub items: Vec<i32>,
+}
+
+impl fmt::Display for Rucksack {
+    /// Write the rucksack's items as letters, as in the input
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for &priority in &self.items {
+            let item = match priority {
+                1..=26 => b'a' + priority as u8 - 1,
+                27..=52 => b'A' + priority as u8 - 27,
+                _ => b'?',
+            };
+            write!(f, "{}", item as char)?;
+        }
+        Ok(())
+    }
+}
+
+#[inline]
+pub fn calculate_priority(c: &u8) -> Option<i32> {
+    match c {
+        b'a'..=b'z' => Some((c - b'a' + 1).into()),
+        b'A'..=b'Z' => Some((c - b'A' + 27).into()),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An item that isn't a letter
+    InvalidItem(Location),
+    /// A rucksack with an odd number of items, which can't be split into two compartments
+    UnevenCompartments(Location),
+    /// A rucksack whose compartments have no item in common
+    NoDuplicate { line: usize },
+    /// A group that starts at `line` but has fewer than 3 elves
+    IncompleteGroup { line: usize },
+    /// A group starting at `line` whose 3 elves have no item in common
+    NoBadge { line: usize },
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidItem(_) => "expected an item in a-z or A-Z".to_string(),
+            Error::UnevenCompartments(_) => "expected an even number of items".to_string(),
+            Error::NoDuplicate { line } => {
+                format!("line {}: compartments have no item in common", line)
+            }
+            Error::IncompleteGroup { line } => {
+                format!("line {}: group has fewer than 3 elves", line)
+            }
+            Error::NoBadge { line } => format!("line {}: group has no badge in common", line),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidItem(loc) | Error::UnevenCompartments(loc) => Some(loc),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day3/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day3::part1(&Day3::parse(EXAMPLE).unwrap()), Ok(157));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day3::part2(&Day3::parse(EXAMPLE).unwrap()), Ok(70));
+    }
+
+    #[test]
+    fn invalid_item() {
+        let err = Day3::parse("vJrwpWtwJgWr\nhcs-MMfFFhFp\n").unwrap_err();
+        assert_eq!(err, Error::InvalidItem(Location::new(1, "hcs-MMfFFhFp", 3)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let rucksacks = Day3::parse(EXAMPLE).unwrap();
+        let printed: String = rucksacks
+            .iter()
+            .map(|rucksack| format!("{}\n", rucksack))
+            .collect();
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(Day3::parse(&printed).unwrap(), rucksacks);
+    }
+}
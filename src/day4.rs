@@ -0,0 +1,159 @@
+/// Day 4: Camp Cleanup
+use std::cmp::{max, min};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{self, Diagnostic, Location};
+use crate::input::normalize;
+use crate::Solution;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Input = Vec<(SectionRange, SectionRange)>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let (r1, r2) = line
+                    .split_once(',')
+                    .ok_or_else(|| Error::MissingComma(Location::new(i, line, line.len())))?;
+                let parse_range = |r: &str| {
+                    r.parse::<SectionRange>()
+                        .map_err(|e| Error::InvalidRange(Location::of(i, line, &r[e.offset..])))
+                };
+                Ok((parse_range(r1)?, parse_range(r2)?))
+            })
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        Ok(pairs
+            .iter()
+            .filter(|(s1, s2)| s1.contains(s2) || s2.contains(s1))
+            .count())
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        Ok(pairs.iter().filter(|(s1, s2)| s1.overlaps(s2)).count())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SectionRange {
+    pub start: i32,
+    pub end: i32,
+}
+
+impl SectionRange {
+    pub fn contains(&self, other: &Self) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        max(self.start, other.start) <= min(self.end, other.end)
+    }
+}
+
+/// A section range that isn't of the form "2-4", failing at byte `offset`
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseSectionRangeError {
+    pub offset: usize,
+}
+
+impl FromStr for SectionRange {
+    type Err = ParseSectionRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start_str, end_str) = s
+            .split_once('-')
+            .ok_or(ParseSectionRangeError { offset: s.len() })?;
+        let start = start_str
+            .parse()
+            .map_err(|_| ParseSectionRangeError { offset: 0 })?;
+        let end = end_str.parse().map_err(|_| ParseSectionRangeError {
+            offset: start_str.len() + 1,
+        })?;
+        Ok(Self { start, end })
+    }
+}
+
+impl fmt::Display for SectionRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A line without a ',' between its two section ranges
+    MissingComma(Location),
+    /// A section range that isn't of the form "2-4"
+    InvalidRange(Location),
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::MissingComma(_) => "expected `,` between ranges".to_string(),
+            Error::InvalidRange(_) => "expected `<int>-<int>`".to_string(),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::MissingComma(loc) | Error::InvalidRange(loc) => Some(loc),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day4/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day4::part1(&Day4::parse(EXAMPLE).unwrap()), Ok(2));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day4::part2(&Day4::parse(EXAMPLE).unwrap()), Ok(4));
+    }
+
+    #[test]
+    fn invalid_range() {
+        let err = Day4::parse("2-4,6-8\n2-3,4-x\n").unwrap_err();
+        assert_eq!(err, Error::InvalidRange(Location::new(1, "2-3,4-x", 6)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let pairs = Day4::parse(EXAMPLE).unwrap();
+        let printed: String = pairs
+            .iter()
+            .map(|(r1, r2)| format!("{},{}\n", r1, r2))
+            .collect();
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(Day4::parse(&printed).unwrap(), pairs);
+    }
+}
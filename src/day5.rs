@@ -0,0 +1,354 @@
+/// Day 5: Supply Stacks
+use std::fmt;
+use std::str::{FromStr, Lines};
+
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::space1,
+    sequence::{pair, preceded, tuple},
+};
+
+use crate::error::{self, Diagnostic, Location};
+use crate::input::normalize;
+use crate::parse::{finish, unsigned};
+use crate::simulation::Simulation;
+use crate::Solution;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        // Count labels to determine number of stacks
+        let n_stacks = input
+            .lines()
+            .find(|line| line.starts_with(" 1"))
+            .ok_or(Error::MissingLabels)?
+            .split_ascii_whitespace()
+            .count();
+
+        let mut lines = input.lines();
+        let stacks = read_stacks(&mut lines, n_stacks);
+        let first_move = input.lines().count() - lines.clone().count();
+        let moves = read_moves(lines, first_move, n_stacks)?;
+
+        Ok(Procedure { stacks, moves })
+    }
+
+    /// A move operation moves 1 crate at a time
+    fn part1(procedure: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        let mut crane = Crane::new(procedure, CrateMover::Model9000);
+        crane.run()?;
+        Ok(format_answer(crane.stacks()))
+    }
+
+    /// A move operation moves all crates in the move together
+    fn part2(procedure: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        let mut crane = Crane::new(procedure, CrateMover::Model9001);
+        crane.run()?;
+        Ok(format_answer(crane.stacks()))
+    }
+}
+
+/// The starting stacks of crates and the rearrangement procedure to apply to them
+#[derive(Clone, Debug)]
+pub struct Procedure {
+    pub stacks: Vec<Vec<Crate>>,
+    pub moves: Vec<Move>,
+}
+
+impl fmt::Display for Procedure {
+    /// Draw the stacks as the puzzle does, without trailing spaces, then list the moves
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row = self
+                .stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(crate_) => crate_.to_string(),
+                    None => "   ".to_string(),
+                })
+                .join(" ");
+            writeln!(f, "{}", row.trim_end())?;
+        }
+        let labels = (1..=self.stacks.len())
+            .map(|label| format!(" {} ", label))
+            .join(" ");
+        writeln!(f, "{}", labels.trim_end())?;
+        writeln!(f)?;
+        for mv in &self.moves {
+            writeln!(f, "{}", mv)?;
+        }
+        Ok(())
+    }
+}
+
+/// Which crane is carrying out the procedure
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrateMover {
+    /// Moves one crate at a time
+    Model9000,
+    /// Moves several crates at once, keeping their order
+    Model9001,
+}
+
+/// A crane working through a procedure's moves, one move per step
+#[derive(Clone, Debug)]
+pub struct Crane<'a> {
+    model: CrateMover,
+    stacks: Vec<Vec<Crate>>,
+    moves: &'a [Move],
+    next: usize,
+}
+
+impl<'a> Crane<'a> {
+    pub fn new(procedure: &'a Procedure, model: CrateMover) -> Self {
+        Crane {
+            model,
+            stacks: procedure.stacks.clone(),
+            moves: &procedure.moves,
+            next: 0,
+        }
+    }
+
+    /// The stacks as they stand, bottom crate first
+    pub fn stacks(&self) -> &[Vec<Crate>] {
+        &self.stacks
+    }
+}
+
+impl Simulation for Crane<'_> {
+    type Snapshot = Vec<Vec<Crate>>;
+    type Error = Error;
+
+    fn step(&mut self) -> Result<(), Self::Error> {
+        let Some(mv) = self.moves.get(self.next) else {
+            return Ok(());
+        };
+        self.next += 1;
+
+        let from = &mut self.stacks[mv.from - 1];
+        let remaining = from
+            .len()
+            .checked_sub(mv.n_items)
+            .ok_or(Error::EmptyStack { mv: self.next })?;
+        let mut crates: Vec<Crate> = from.drain(remaining..).collect();
+        if self.model == CrateMover::Model9000 {
+            // Moving crates one at a time reverses their order
+            crates.reverse();
+        }
+        self.stacks[mv.to - 1].extend(crates);
+        Ok(())
+    }
+
+    fn is_done(&self) -> bool {
+        self.next == self.moves.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.stacks.clone()
+    }
+}
+
+/// Read crates from the provided input lines onto the stacks
+fn read_stacks(lines: &mut Lines, n_stacks: usize) -> Vec<Vec<Crate>> {
+    let mut stacks: Vec<Vec<Crate>> = vec![];
+    for _ in 0..n_stacks {
+        stacks.push(vec![]);
+    }
+    for line in lines {
+        if line.trim().is_empty() {
+            break; // end of stack section
+        }
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            if let Some(c) = line.chars().nth(1 + i * 4) {
+                if let Some(crate_) = Crate::new(c) {
+                    stack.push(crate_);
+                }
+            }
+        }
+    }
+    // Reverse the stacks since we built them upside-down
+    for stack in stacks.iter_mut() {
+        stack.reverse();
+    }
+    stacks
+}
+
+/// Read moves from the provided input lines into a vector, skipping blank lines
+///
+/// `first_line` is the index of the first of `lines` in the input, for error locations.
+fn read_moves(lines: Lines, first_line: usize, n_stacks: usize) -> Result<Vec<Move>, Error> {
+    lines
+        .enumerate()
+        .map(|(i, line)| (first_line + i, line))
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let mv: Move = line.parse().map_err(|e: ParseMoveError| {
+                Error::InvalidMove(Location::new(i, line, e.offset))
+            })?;
+            if !(1..=n_stacks).contains(&mv.from) || !(1..=n_stacks).contains(&mv.to) {
+                return Err(Error::UnknownStack(Location::new(i, line, 0)));
+            }
+            Ok(mv)
+        })
+        .collect()
+}
+
+/// Format the answer string from the stack of crates
+fn format_answer(stacks: &[Vec<Crate>]) -> String {
+    stacks
+        .iter()
+        .filter_map(|stack| stack.last().map(|top_crate| top_crate.id))
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub from: usize,
+    pub to: usize,
+    pub n_items: usize,
+}
+
+/// A move line that isn't of the form "move 7 from 3 to 9", failing at byte `offset`
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseMoveError {
+    pub offset: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseMoveError;
+
+    /// Parse a line of the form
+    ///     "move 7 from 3 to 9"
+    /// to
+    ///     Move { n_items: 7, from: 3, to: 9 }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |keyword| preceded(pair(tag(keyword), space1), unsigned);
+        let parser = tuple((
+            number("move"),
+            preceded(space1, number("from")),
+            preceded(space1, number("to")),
+        ));
+        let (n_items, from, to) = finish(s, parser).map_err(|offset| ParseMoveError { offset })?;
+        Ok(Move { from, to, n_items })
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.n_items, self.from, self.to)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Crate {
+    pub id: char,
+}
+
+impl Crate {
+    /// A crate with the given id, which must be in 'A'-'Z'
+    pub fn new(id: char) -> Option<Self> {
+        match id {
+            'A'..='Z' => Some(Self { id }),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Crate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}]", self.id)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Input without the line of stack numbers below the crates
+    MissingLabels,
+    /// A line that isn't of the form "move N from A to B"
+    InvalidMove(Location),
+    /// A move to or from a stack that doesn't exist
+    UnknownStack(Location),
+    /// Move number `mv`, counting from 1, takes more crates than its stack holds
+    EmptyStack { mv: usize },
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::MissingLabels => "input should contain a line of stack numbers".to_string(),
+            Error::InvalidMove(_) => "expected `move N from A to B`".to_string(),
+            Error::UnknownStack(_) => "move to or from unknown stack".to_string(),
+            Error::EmptyStack { mv } => format!("move {} takes from an empty stack", mv),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidMove(loc) | Error::UnknownStack(loc) => Some(loc),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day5/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            Day5::part1(&Day5::parse(EXAMPLE).unwrap()),
+            Ok("CMZ".to_string())
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day5::part2(&Day5::parse(EXAMPLE).unwrap()),
+            Ok("MCD".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_move() {
+        let input = EXAMPLE.replace("move 3 from 1 to 3", "move 3 form 1 to 3");
+        let err = Day5::parse(&input).unwrap_err();
+        assert_eq!(
+            err,
+            Error::InvalidMove(Location::new(6, "move 3 form 1 to 3", 7))
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let procedure = Day5::parse(EXAMPLE).unwrap();
+        let printed = procedure.to_string();
+        assert_eq!(printed, normalize(EXAMPLE));
+        let reparsed = Day5::parse(&printed).unwrap();
+        assert_eq!(reparsed.stacks, procedure.stacks);
+        assert_eq!(reparsed.moves, procedure.moves);
+    }
+}
@@ -0,0 +1,138 @@
+/// Day 6: Tuning Trouble
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::error::{self, Diagnostic, Location};
+use crate::input::normalize;
+use crate::Solution;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        // The datastream is a single line, without the newline that ends it
+        Ok(input.trim_end().to_string())
+    }
+
+    /// Find the start-of-packet marker
+    fn part1(datastream: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        find_marker(datastream, 4).ok_or(Error::NoMarker { window_size: 4 })
+    }
+
+    /// Find the start-of-message marker
+    fn part2(datastream: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        find_marker(datastream, 14).ok_or(Error::NoMarker { window_size: 14 })
+    }
+}
+
+/// Position just past the first window of `window_size` distinct characters
+pub fn find_marker(input: &str, window_size: usize) -> Option<usize> {
+    let mut set = HashSet::with_capacity(window_size);
+
+    input
+        .as_bytes()
+        .windows(window_size)
+        .enumerate()
+        .find_map(|(i, window)| match is_unique(window, &mut set) {
+            true => Some(i + window_size),
+            false => None,
+        })
+}
+
+fn is_unique(arr: &[u8], set: &mut HashSet<u8>) -> bool {
+    set.clear();
+    let mut unique = true;
+    for b in arr {
+        if !set.insert(*b) {
+            unique = false;
+            break;
+        }
+    }
+    unique
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A datastream without `window_size` distinct characters in a row
+    NoMarker { window_size: usize },
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::NoMarker { window_size } => {
+                format!("no marker of {} distinct characters", window_size)
+            }
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        None
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day6/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day6::part1(&Day6::parse(EXAMPLE).unwrap()), Ok(7));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day6::part2(&Day6::parse(EXAMPLE).unwrap()), Ok(19));
+    }
+
+    #[test]
+    fn marker_excludes_newline() {
+        let datastream = Day6::parse("abcc\r\n").unwrap();
+        assert_eq!(datastream, "abcc");
+        assert_eq!(
+            Day6::part1(&Day6::parse("aabc\n").unwrap()),
+            Err(Error::NoMarker { window_size: 4 })
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let datastream = Day6::parse(EXAMPLE).unwrap();
+        let printed = format!("{}\n", datastream);
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(Day6::parse(&printed).unwrap(), datastream);
+    }
+
+    #[test]
+    fn more_examples() {
+        let examples = [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+        for (datastream, packet, message) in examples {
+            assert_eq!(find_marker(datastream, 4), Some(packet));
+            assert_eq!(find_marker(datastream, 14), Some(message));
+        }
+    }
+}
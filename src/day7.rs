@@ -0,0 +1,353 @@
+/// Day 7: No Space Left On Device
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::error::{self, Diagnostic, Location};
+use crate::input::normalize;
+use crate::Solution;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+
+    type Input = FileSystem;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        let mut filesystem = FileSystem::new();
+
+        for (i, line) in input.lines().enumerate() {
+            match tokenize(line)
+                .map_err(|offset| Error::InvalidLine(Location::new(i, line, offset)))?
+            {
+                Token::Cmd(Cmd::Cd(dir)) => filesystem
+                    .cd(dir)
+                    .map_err(|_| Error::UnknownDirectory(Location::of(i, line, dir)))?,
+                Token::Cmd(Cmd::Ls) => { /* noop */ }
+                Token::File { name, size } => filesystem.add_file(name.to_string(), size),
+                Token::Directory { name } => filesystem.mkdir(name.to_string()),
+            }
+        }
+
+        Ok(filesystem)
+    }
+
+    /// Sum of all directories less than 100 kB
+    fn part1(filesystem: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        Ok(directory_sizes(filesystem)
+            .into_iter()
+            .filter(|&s| s < 100_000)
+            .sum())
+    }
+
+    /// Find the smallest directory that gives 30 MB free space
+    ///
+    /// That's the smallest directory of all if there's already enough free space.
+    fn part2(filesystem: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        let mut sizes = directory_sizes(filesystem);
+        sizes.reverse();
+        sizes.sort_unstable();
+
+        let total: usize = 70_000_000;
+        let needed: usize = 30_000_000;
+        let used = filesystem.root.borrow().size();
+        let free = total.saturating_sub(used);
+        let must_delete = needed.saturating_sub(free);
+        sizes
+            .into_iter()
+            .find(|&s| s >= must_delete)
+            .ok_or(Error::NotEnoughSpace { used })
+    }
+}
+
+/// Total size of every directory, starting with root in depth-first order
+pub fn directory_sizes(fs: &FileSystem) -> Vec<usize> {
+    let mut sizes: Vec<usize> = vec![];
+    fn dsize(dir: Rc<RefCell<Directory>>, sizes: &mut Vec<usize>) {
+        sizes.push(dir.borrow().size());
+        for subdir in dir.borrow().subdirectories() {
+            dsize(Rc::clone(subdir), sizes);
+        }
+    }
+    dsize(Rc::clone(&fs.root), &mut sizes);
+    sizes
+}
+
+/// Tokenize a line of terminal output, returning the offset of the first invalid byte on error
+fn tokenize(line: &str) -> Result<Token<'_>, usize> {
+    match line {
+        l if l.starts_with('$') => match l.get(2..4) {
+            Some("cd") => match l.get(5..) {
+                Some(dir) if l.as_bytes()[4] == b' ' && !dir.is_empty() => {
+                    Ok(Token::Cmd(Cmd::Cd(dir)))
+                }
+                _ => Err(4),
+            },
+            Some("ls") if l.len() == 4 => Ok(Token::Cmd(Cmd::Ls)),
+            _ => Err(2),
+        },
+        l if l.starts_with("dir") => match l.strip_prefix("dir ") {
+            Some(name) if !name.is_empty() => Ok(Token::Directory { name }),
+            _ => Err(3),
+        },
+        file => {
+            let (size, name) = file.split_once(' ').ok_or(file.len())?;
+            Ok(Token::File {
+                name,
+                size: size.parse().map_err(|_| 0usize)?,
+            })
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Token<'a> {
+    Cmd(Cmd<'a>),
+    File { name: &'a str, size: usize },
+    Directory { name: &'a str },
+}
+
+#[derive(Debug)]
+enum Cmd<'a> {
+    Cd(&'a str),
+    Ls,
+}
+
+pub struct FileSystem {
+    root: Rc<RefCell<Directory>>,
+    current: Rc<RefCell<Directory>>,
+}
+
+impl FileSystem {
+    pub fn new() -> FileSystem {
+        let root = Rc::new(RefCell::new(Directory::new()));
+        let current = Rc::clone(&root);
+        FileSystem { root, current }
+    }
+
+    pub fn mkdir(&mut self, name: String) {
+        let mut current = self.current.borrow_mut();
+
+        let dir = Rc::new(RefCell::new(Directory {
+            name,
+            parent: Some(Rc::clone(&self.current)),
+            entries: vec![],
+        }));
+
+        current.entries.push(Entry::Directory(dir));
+    }
+
+    /// Change the current directory, failing if `dir` is not a subdirectory of it
+    pub fn cd(&mut self, dir: &str) -> Result<(), DirectoryNotFound> {
+        match dir {
+            "/" => {
+                self.current = Rc::clone(&self.root);
+            }
+            ".." => {
+                let parent = self.current.borrow().parent.clone();
+                if let Some(parent) = parent {
+                    self.current = parent;
+                } // else already at root, do nothing
+            }
+            _ => {
+                let newdir = Rc::clone(
+                    self.current
+                        .borrow()
+                        .subdirectories()
+                        .find(|d| d.borrow().name == dir)
+                        .ok_or(DirectoryNotFound)?,
+                );
+                self.current = newdir;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn add_file(&mut self, name: String, size: usize) {
+        let file = File { name, size };
+        self.current.borrow_mut().entries.push(Entry::File(file));
+    }
+}
+
+impl fmt::Display for FileSystem {
+    /// Write a transcript that lists every directory depth first, each entry in the order it was
+    /// listed, without changing back up out of the last directory visited
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn explore(dir: &Directory, lines: &mut Vec<String>) {
+            lines.push("$ ls".to_string());
+            for entry in &dir.entries {
+                lines.push(match entry {
+                    Entry::Directory(subdir) => format!("dir {}", subdir.borrow().name),
+                    Entry::File(file) => format!("{} {}", file.size, file.name),
+                });
+            }
+            for subdir in dir.subdirectories() {
+                lines.push(format!("$ cd {}", subdir.borrow().name));
+                explore(&subdir.borrow(), lines);
+                lines.push("$ cd ..".to_string());
+            }
+        }
+
+        let mut lines = vec!["$ cd /".to_string()];
+        explore(&self.root.borrow(), &mut lines);
+        while lines.last().is_some_and(|line| line == "$ cd ..") {
+            lines.pop();
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DirectoryNotFound;
+
+#[derive(Debug, PartialEq)]
+struct File {
+    name: String,
+    size: usize,
+}
+
+/// A line of a directory's listing
+#[derive(Debug)]
+enum Entry {
+    Directory(Rc<RefCell<Directory>>),
+    File(File),
+}
+
+#[derive(Debug)]
+struct Directory {
+    name: String,
+    parent: Option<Rc<RefCell<Directory>>>,
+    /// Subdirectories and files, in the order they were listed
+    entries: Vec<Entry>,
+}
+
+impl Directory {
+    /// Create a root directory
+    pub fn new() -> Directory {
+        Directory {
+            name: String::from("/"),
+            parent: None,
+            entries: vec![],
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.entries
+            .iter()
+            .map(|entry| match entry {
+                Entry::Directory(dir) => dir.borrow().size(),
+                Entry::File(file) => file.size,
+            })
+            .sum()
+    }
+
+    fn subdirectories(&self) -> impl Iterator<Item = &Rc<RefCell<Directory>>> {
+        self.entries.iter().filter_map(|entry| match entry {
+            Entry::Directory(dir) => Some(dir),
+            Entry::File(_) => None,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't a `cd` or `ls` command, or a line of `ls` output
+    InvalidLine(Location),
+    /// A `cd` into a directory that hasn't been listed
+    UnknownDirectory(Location),
+    /// A filesystem so full that deleting every directory doesn't free up enough space
+    NotEnoughSpace { used: usize },
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidLine(_) => {
+                "expected `$ cd <dir>`, `$ ls`, `dir <name>` or `<size> <name>`".to_string()
+            }
+            Error::UnknownDirectory(_) => "no such directory".to_string(),
+            Error::NotEnoughSpace { used } => {
+                format!("cannot free enough space with {} bytes used", used)
+            }
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidLine(loc) | Error::UnknownDirectory(loc) => Some(loc),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day7/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day7::part1(&Day7::parse(EXAMPLE).unwrap()), Ok(95437));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day7::part2(&Day7::parse(EXAMPLE).unwrap()), Ok(24933642));
+    }
+
+    #[test]
+    fn unknown_directory() {
+        let result = Day7::parse("$ cd /\n$ ls\ndir a\n$ cd b\n");
+        assert_eq!(
+            result.err(),
+            Some(Error::UnknownDirectory(Location::new(3, "$ cd b", 5)))
+        );
+    }
+
+    #[test]
+    fn nothing_to_delete() {
+        let filesystem = Day7::parse("$ cd /\n$ ls\n").unwrap();
+        assert_eq!(Day7::part2(&filesystem), Ok(0));
+        let filesystem = Day7::parse("$ cd /\n$ ls\ndir a\n20 b\n").unwrap();
+        assert_eq!(Day7::part2(&filesystem), Ok(0));
+    }
+
+    #[test]
+    fn delete_exactly_enough() {
+        let transcript = "$ cd /\n$ ls\ndir a\n40000000 b\n$ cd a\n$ ls\n10000000 c\n";
+        let filesystem = Day7::parse(transcript).unwrap();
+        assert_eq!(Day7::part2(&filesystem), Ok(10_000_000));
+    }
+
+    #[test]
+    fn round_trip() {
+        let filesystem = Day7::parse(EXAMPLE).unwrap();
+        let printed = format!("{}\n", filesystem);
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(
+            Day7::parse(&printed).unwrap().to_string(),
+            filesystem.to_string()
+        );
+    }
+}
@@ -0,0 +1,165 @@
+/// Day 8: Treetop Tree House
+use std::fmt;
+
+use crate::error::{self, Diagnostic, Location};
+use crate::grid::{Grid, ParseGridError, Position, ORTHOGONAL};
+use crate::input::normalize;
+use crate::Solution;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+
+    type Input = Forest;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        const BASE10: u32 = 10;
+        Grid::parse(input, |c| c.to_digit(BASE10)).map_err(|e| match e {
+            ParseGridError::InvalidCell(loc) => Error::InvalidHeight(loc),
+            ParseGridError::RaggedRow(loc) => Error::RaggedRow(loc),
+            ParseGridError::Empty => Error::Empty,
+        })
+    }
+
+    fn part1(forest: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        Ok(visible_trees(forest))
+    }
+
+    fn part2(forest: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        Ok(best_scenic_score(forest))
+    }
+}
+
+/// Tree heights
+pub type Forest = Grid<u32>;
+
+/// Positions of the trees not on the edge of the forest
+fn interior(forest: &Forest) -> impl Iterator<Item = Position> + '_ {
+    forest.positions().filter(|&(row, col)| {
+        row > 0 && col > 0 && row < forest.height() - 1 && col < forest.width() - 1
+    })
+}
+
+// Part 2: Find the most scenic view.
+pub fn best_scenic_score(forest: &Forest) -> usize {
+    fn score_view<'a>(this: u32, others: impl Iterator<Item = &'a u32>) -> usize {
+        let mut score = 0;
+        for other in others {
+            score += 1;
+            if *other >= this {
+                break;
+            }
+        }
+        score
+    }
+
+    // Don't consider edge trees, since a score on one edge of 0 makes the
+    // total scenic score 0.
+    interior(forest)
+        .map(|position| {
+            let this_tree = forest[position];
+            ORTHOGONAL
+                .iter()
+                .map(|&step| score_view(this_tree, forest.ray(position, step)))
+                .product()
+        })
+        // Forests too small to have interior trees only have edge trees, which all score 0.
+        .max()
+        .unwrap_or(0)
+}
+
+// Part 1: Find the number of trees that are visible from outside.
+pub fn visible_trees(forest: &Forest) -> usize {
+    // Forests too thin to have interior trees are all perimeter.
+    if forest.height() <= 2 || forest.width() <= 2 {
+        return forest.height() * forest.width();
+    }
+
+    // All of the outside perimeter are visible.
+    let perimeter = forest.height() * 2 + (forest.width() - 2) * 2;
+
+    // An interior tree is visible if every tree is shorter in any one of the
+    // directions up, down, left or right.
+    let interior_visible = interior(forest)
+        .filter(|&position| {
+            let height = forest[position];
+            ORTHOGONAL
+                .iter()
+                .any(|&step| forest.ray(position, step).all(|&h| h < height))
+        })
+        .count();
+
+    perimeter + interior_visible
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A tree height that isn't a digit
+    InvalidHeight(Location),
+    /// A row whose length differs from the first row's
+    RaggedRow(Location),
+    /// Input without any trees
+    Empty,
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidHeight(_) => "expected a digit".to_string(),
+            Error::RaggedRow(_) => "expected the same number of trees in every row".to_string(),
+            Error::Empty => "input contains no trees".to_string(),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidHeight(loc) | Error::RaggedRow(loc) => Some(loc),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day8/example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day8::part1(&Day8::parse(EXAMPLE).unwrap()), Ok(21));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day8::part2(&Day8::parse(EXAMPLE).unwrap()), Ok(8));
+    }
+
+    #[test]
+    fn ragged_row() {
+        let err = Day8::parse("303\n25\n653\n").unwrap_err();
+        assert_eq!(err, Error::RaggedRow(Location::new(1, "25", 2)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let forest = Day8::parse(EXAMPLE).unwrap();
+        let printed = format!("{}\n", forest);
+        assert_eq!(printed, normalize(EXAMPLE));
+        assert_eq!(Day8::parse(&printed).unwrap(), forest);
+    }
+}
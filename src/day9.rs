@@ -0,0 +1,264 @@
+/// Day 9: Rope Bridge
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::animation::{Animation, Frame};
+use crate::error::{self, Diagnostic, Location};
+use crate::geometry::{Direction, Point, Vector};
+use crate::input::normalize;
+use crate::simulation::Simulation;
+use crate::Solution;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+
+    type Input = Vec<Motion>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let input: &str = &normalize(input);
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let Some((d, c)) = line.split_once(' ') else {
+                    return Err(Error::InvalidMotion(Location::new(i, line, line.len())));
+                };
+                let direction = Direction::from_str(d)
+                    .map_err(|_| Error::InvalidDirection(Location::of(i, line, d)))?;
+                let count =
+                    i32::from_str(c).map_err(|_| Error::InvalidCount(Location::of(i, line, c)))?;
+                Ok(Motion { direction, count })
+            })
+            .collect()
+    }
+
+    fn part1(motions: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        Ok(tail_visits(motions, 2))
+    }
+
+    fn part2(motions: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        Ok(tail_visits(motions, 10))
+    }
+}
+
+/// One line of the input: move the head `count` steps in `direction`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Motion {
+    pub direction: Direction,
+    pub count: i32,
+}
+
+impl fmt::Display for Motion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.direction, self.count)
+    }
+}
+
+/// Count the unique locations visited by the last knot of a rope of `n_knots` knots
+pub fn tail_visits(motions: &[Motion], n_knots: usize) -> usize {
+    let mut rope = Rope::new(motions, n_knots);
+    let Ok(()) = rope.run();
+    rope.visited().len()
+}
+
+/// A rope being pulled through a series of motions, one square per step
+///
+/// Instead of a distinct head and tail, I just track N (1 head + N-1 tails) "knots". For the
+/// head knot, I apply one step of a motion from the input file, then apply the "following" rules
+/// to each tail knot in sequence. The last knot is cloned into a hashset to track unique
+/// locations.
+#[derive(Clone, Debug)]
+pub struct Rope<'a> {
+    knots: Vec<Point>,
+    motions: &'a [Motion],
+    /// Index of the motion in progress, and how many steps of it have been taken
+    current: usize,
+    taken: i32,
+    visited: HashSet<Point>,
+}
+
+impl<'a> Rope<'a> {
+    /// A rope of `n_knots` knots (at least one) starting at the origin
+    pub fn new(motions: &'a [Motion], n_knots: usize) -> Self {
+        let knots = vec![Point::ORIGIN; n_knots.max(1)];
+        let mut rope = Rope {
+            knots,
+            motions,
+            current: 0,
+            taken: 0,
+            visited: HashSet::from([Point::ORIGIN]),
+        };
+        rope.skip_finished();
+        rope
+    }
+
+    /// Every knot's position, head first
+    pub fn knots(&self) -> &[Point] {
+        &self.knots
+    }
+
+    /// The unique locations visited by the last knot so far
+    pub fn visited(&self) -> &HashSet<Point> {
+        &self.visited
+    }
+
+    /// Move on past any motions with no steps left to take
+    fn skip_finished(&mut self) {
+        while let Some(motion) = self.motions.get(self.current) {
+            if self.taken < motion.count {
+                break;
+            }
+            self.current += 1;
+            self.taken = 0;
+        }
+    }
+}
+
+impl Simulation for Rope<'_> {
+    type Snapshot = Vec<Point>;
+    type Error = Infallible;
+
+    fn step(&mut self) -> Result<(), Self::Error> {
+        let Some(motion) = self.motions.get(self.current) else {
+            return Ok(());
+        };
+        self.knots[0] += motion.direction.vector();
+        for i in 1..self.knots.len() {
+            let offset = tail_offset(self.knots[i - 1], self.knots[i]);
+            self.knots[i] += offset;
+        }
+        self.visited.insert(*self.knots.last().unwrap()); // track the last knot
+
+        self.taken += 1;
+        self.skip_finished();
+        Ok(())
+    }
+
+    fn is_done(&self) -> bool {
+        self.current == self.motions.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.knots.clone()
+    }
+}
+
+/// Return an offset that tail can apply to stay less than one space away
+/// from head
+pub fn tail_offset(head: Point, tail: Point) -> Vector {
+    match head.chebyshev_distance(tail) {
+        0 | 1 => Point::ORIGIN,
+        _ => (head - tail).signum(),
+    }
+}
+
+/// The head's path is traced as it goes: `H` and the numbered knots (or `T` for a two-knot rope)
+/// over the starting point `s` and the squares the tail has visited, `#`
+impl Animation for Rope<'_> {
+    fn focus(&self) -> Point {
+        self.knots[0]
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        frame.fill(|p| match self.visited.contains(&p) {
+            true => '#',
+            false => '.',
+        });
+        frame.draw(Point::ORIGIN, 's');
+        // Draw from the tail up, so that knots nearer the head are drawn on top
+        for (i, &knot) in self.knots.iter().enumerate().rev() {
+            let label = match (i, self.knots.len()) {
+                (0, _) => 'H',
+                (_, 2) => 'T',
+                _ => char::from_digit(i as u32 % 10, 10).unwrap(),
+            };
+            frame.draw(knot, label);
+        }
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "motion {} of {}, {} positions visited by the tail",
+            (self.current + 1).min(self.motions.len()),
+            self.motions.len(),
+            self.visited.len()
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A line that isn't a direction and a count separated by a space
+    InvalidMotion(Location),
+    /// A direction other than R, L, U or D
+    InvalidDirection(Location),
+    /// A count that isn't an integer
+    InvalidCount(Location),
+}
+
+impl Diagnostic for Error {
+    fn hint(&self) -> String {
+        match self {
+            Error::InvalidMotion(_) => "expected `<direction> <count>`".to_string(),
+            Error::InvalidDirection(_) => "expected R, L, U or D".to_string(),
+            Error::InvalidCount(_) => "expected an integer count".to_string(),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            Error::InvalidMotion(loc) | Error::InvalidDirection(loc) | Error::InvalidCount(loc) => {
+                Some(loc)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("bin/day9/example.txt");
+    const LARGER_EXAMPLE: &str = include_str!("bin/day9/example2.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day9::part1(&Day9::parse(EXAMPLE).unwrap()), Ok(13));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day9::part2(&Day9::parse(EXAMPLE).unwrap()), Ok(1));
+        assert_eq!(Day9::part2(&Day9::parse(LARGER_EXAMPLE).unwrap()), Ok(36));
+    }
+
+    #[test]
+    fn invalid_direction() {
+        let err = Day9::parse("R 4\nX 4\n").unwrap_err();
+        assert_eq!(err, Error::InvalidDirection(Location::new(1, "X 4", 0)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let motions = Day9::parse(LARGER_EXAMPLE).unwrap();
+        let printed: String = motions.iter().map(|m| format!("{}\n", m)).collect();
+        assert_eq!(printed, normalize(LARGER_EXAMPLE));
+        assert_eq!(Day9::parse(&printed).unwrap(), motions);
+    }
+}
@@ -0,0 +1,201 @@
+//! Locations in the puzzle input, shared by every day's error type.
+use std::fmt;
+
+/// Where in the puzzle input an error was found
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column in characters, starting at 1
+    pub column: usize,
+    /// Text of the offending line
+    pub text: String,
+}
+
+impl Location {
+    /// Locate byte `offset` within `line`, which is line `index` (counting from 0) of the input
+    pub fn new(index: usize, line: &str, offset: usize) -> Self {
+        let column = line.char_indices().take_while(|(i, _)| *i < offset).count() + 1;
+        Location {
+            line: index + 1,
+            column,
+            text: line.to_string(),
+        }
+    }
+
+    /// Locate byte `offset` within the whole of a multi-line `input`
+    pub fn at(input: &str, offset: usize) -> Self {
+        let offset = offset.min(input.len());
+        let index = input[..offset].matches('\n').count();
+        let start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line = input[start..].lines().next().unwrap_or_default();
+        Self::new(index, line, offset - start)
+    }
+
+    /// Locate the start of `field`, which must be a slice of `line`
+    pub fn of(index: usize, line: &str, field: &str) -> Self {
+        let offset = (field.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        Self::new(index, line, offset)
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: `{}`",
+            self.line, self.column, self.text
+        )
+    }
+}
+
+/// An error that may point at a place in the puzzle input
+///
+/// Every day's error type implements this, so that malformed input can be reported the same way
+/// for every day: in one line with [`describe`], or underlined in context with [`Report`].
+pub trait Diagnostic: std::error::Error {
+    /// What was expected or went wrong, without saying where
+    fn hint(&self) -> String;
+
+    /// Where in the input the error was found, if it concerns a particular place
+    fn location(&self) -> Option<&Location>;
+}
+
+/// Describe `error` in one line: its hint, followed by its location if it has one
+///
+/// Day error types implement [`Display`](fmt::Display) with this.
+pub fn describe(error: &(impl Diagnostic + ?Sized), f: &mut fmt::Formatter) -> fmt::Result {
+    match error.location() {
+        Some(loc) => write!(f, "{} at {}", error.hint(), loc),
+        None => write!(f, "{}", error.hint()),
+    }
+}
+
+/// A [`Diagnostic`] detached from its error, to be rendered once the input's name is known
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    pub hint: String,
+    pub location: Option<Location>,
+}
+
+impl Report {
+    pub fn new(error: &(impl Diagnostic + ?Sized)) -> Self {
+        Report {
+            hint: error.hint(),
+            location: error.location().cloned(),
+        }
+    }
+
+    /// Render as a multi-line diagnostic naming `file`, with the offending line quoted and the
+    /// token at the error's column underlined
+    ///
+    /// ```text
+    /// error: expected `move N from A to B`
+    ///  --> input.txt:3:6
+    ///   |
+    /// 3 | move x from 1 to 2
+    ///   |      ^
+    /// ```
+    pub fn render(&self, file: &str) -> String {
+        let Some(loc) = &self.location else {
+            return format!("error: {}\n --> {}\n", self.hint, file);
+        };
+
+        let number = loc.line.to_string();
+        let gutter = " ".repeat(number.len());
+        // Underline from the column to the end of the token there, or a single caret past the
+        // end of the line
+        let token = loc
+            .text
+            .chars()
+            .skip(loc.column - 1)
+            .take_while(|c| !c.is_whitespace())
+            .count();
+        let indent: String = loc
+            .text
+            .chars()
+            .take(loc.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        format!(
+            "error: {hint}\n{gutter}--> {file}:{line}:{column}\n{gutter} |\n{number} | {text}\n{gutter} | {indent}{carets}\n",
+            hint = self.hint,
+            line = loc.line,
+            column = loc.column,
+            text = loc.text,
+            carets = "^".repeat(token.max(1)),
+        )
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        describe(self, f)
+    }
+}
+
+impl std::error::Error for Report {}
+
+impl Diagnostic for Report {
+    fn hint(&self) -> String {
+        self.hint.clone()
+    }
+
+    fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_with_location() {
+        let report = Report {
+            hint: "expected `move N from A to B`".to_string(),
+            location: Some(Location::new(2, "move x from 1 to 2", 5)),
+        };
+        let expected = [
+            "error: expected `move N from A to B`",
+            " --> input.txt:3:6",
+            "  |",
+            "3 | move x from 1 to 2",
+            "  |      ^",
+            "",
+        ];
+        assert_eq!(report.render("input.txt"), expected.join("\n"));
+        assert_eq!(
+            report.to_string(),
+            "expected `move N from A to B` at line 3, column 6: `move x from 1 to 2`"
+        );
+    }
+
+    #[test]
+    fn render_underlines_token() {
+        let report = Report {
+            hint: "expected `addx <i32>` or `noop`".to_string(),
+            location: Some(Location::new(11, "addx ten", 5)),
+        };
+        let rendered = report.render("-");
+        assert!(rendered.contains("\n  --> -:12:6\n"), "{}", rendered);
+        assert!(
+            rendered.ends_with("12 | addx ten\n   |      ^^^\n"),
+            "{}",
+            rendered
+        );
+    }
+
+    #[test]
+    fn render_without_location() {
+        let report = Report {
+            hint: "input contains no elves".to_string(),
+            location: None,
+        };
+        assert_eq!(
+            report.render("input.txt"),
+            "error: input contains no elves\n --> input.txt\n"
+        );
+    }
+}
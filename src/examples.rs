@@ -0,0 +1,331 @@
+//! Examples and their answers, extracted from puzzle pages saved from the Advent of Code site.
+//!
+//! A puzzle page has a `<article class="day-desc">` for each part whose description has been
+//! unlocked. Example input is shown in `<pre><code>` blocks, and the answer each part gives for
+//! the example is emphasized as `<code><em>…</em></code>`. Lists walk through the working, or give
+//! answers for more examples, so the answer is the first emphasized code outside of a list, or in
+//! the first list item if it's all in a list. Extracted answers are recorded in `examples.toml`, in the same format as
+//! `answers.toml` (see [`answers`](crate::answers)), and checked against each day's
+//! `example.txt` by the tests.
+use std::path::{Path, PathBuf};
+
+use crate::runner::Part;
+
+/// The parts of a saved puzzle page that make test fixtures
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Page {
+    /// The day in the page's title, if it has one
+    pub day: Option<u8>,
+    /// Each unlocked part's description, part 1's first
+    pub descriptions: Vec<Description>,
+}
+
+/// The examples and emphasized answers in one part's description
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Description {
+    /// The text of each `<pre><code>` block, in order
+    pub blocks: Vec<String>,
+    /// The text of each emphasized piece of code outside of those blocks and lists, in order
+    pub emphasized: Vec<String>,
+    /// The text of each emphasized piece of code in a list item, in order
+    pub listed: Vec<String>,
+}
+
+impl Description {
+    /// The answer for the example: the first thing emphasized outside of a list, if anything is
+    pub fn answer(&self) -> Option<&str> {
+        let mut emphasized = self.emphasized.iter().chain(&self.listed);
+        emphasized.next().map(String::as_str)
+    }
+}
+
+impl Page {
+    /// Extract the examples and answers from a page's HTML
+    pub fn parse(html: &str) -> Self {
+        let day = find_all(html, "<h2>--- Day ", ":")
+            .first()
+            .and_then(|(_, day)| day.trim().parse().ok());
+
+        let descriptions = find_all(html, "<article class=\"day-desc\">", "</article>")
+            .into_iter()
+            .map(|(_, article)| {
+                let pre = find_all(article, "<pre><code>", "</code></pre>");
+                let blocks = pre
+                    .iter()
+                    .map(|(_, block)| {
+                        let mut block = text(block);
+                        if !block.ends_with('\n') {
+                            block.push('\n');
+                        }
+                        block
+                    })
+                    .collect();
+
+                // Emphasis within example blocks highlights part of the example, not an answer
+                let li = find_all(article, "<li>", "</li>");
+                let within = |found: &[(usize, &str)], i: usize| {
+                    found
+                        .iter()
+                        .any(|(start, s)| (*start..start + s.len()).contains(&i))
+                };
+                let mut emphasized = find_all(article, "<code><em>", "</em></code>");
+                emphasized.extend(find_all(article, "<em><code>", "</code></em>"));
+                emphasized.retain(|(i, _)| !within(&pre, *i));
+                emphasized.sort();
+                let (listed, emphasized): (Vec<_>, Vec<_>) =
+                    emphasized.into_iter().partition(|(i, _)| within(&li, *i));
+                let text_of = |found: Vec<(usize, &str)>| {
+                    found.into_iter().map(|(_, code)| text(code)).collect()
+                };
+
+                Description {
+                    blocks,
+                    emphasized: text_of(emphasized),
+                    listed: text_of(listed),
+                }
+            })
+            .collect();
+
+        Page { day, descriptions }
+    }
+
+    /// The example input, taken to be the first block in part 1's description
+    pub fn example(&self) -> Option<&str> {
+        self.descriptions
+            .first()?
+            .blocks
+            .first()
+            .map(String::as_str)
+    }
+
+    /// The answer each unlocked part gives for the example
+    pub fn answers(&self) -> Vec<(Part, &str)> {
+        [Part::One, Part::Two]
+            .into_iter()
+            .zip(&self.descriptions)
+            .filter_map(|(part, description)| Some((part, description.answer()?)))
+            .collect()
+    }
+}
+
+/// The comment at the top of the example answers file
+pub const HEADER: &str = "\
+# Answers for the examples in src/bin/dayN/example.txt, from each day's puzzle page
+#
+# Record a day's examples with `cargo run --bin extract -- PAGE`, where PAGE is the saved puzzle page.
+#
+# Day 10's part 2 answer is an image the page shows in a block rather than emphasizing, so it was
+# recorded by hand. Day 15 is left out: its example asks about row 10 and a search area up to 20,
+# but the solver always uses the real input's row 2000000 and 4000000, so it gives other answers.
+
+";
+
+/// The well-known location of the example answers file: `examples.toml` in the root of this crate
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("examples.toml")
+}
+
+/// Each substring of `s` between an `open` and the next `close`, with its byte offset
+fn find_all<'a>(s: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut found = vec![];
+    let mut rest = 0;
+    while let Some(start) = s[rest..].find(open).map(|i| rest + i + open.len()) {
+        let Some(end) = s[start..].find(close).map(|i| start + i) else {
+            break;
+        };
+        found.push((start, &s[start..end]));
+        rest = end + close.len();
+    }
+    found
+}
+
+/// The text of some HTML, without its tags and with entities decoded
+fn text(html: &str) -> String {
+    let mut stripped = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+    decode(&stripped)
+}
+
+/// Decode the character references in some HTML text
+fn decode(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(i) = rest.find('&') {
+        decoded.push_str(&rest[..i]);
+        rest = &rest[i..];
+
+        let reference = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let c = reference.and_then(|reference| match reference {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => {
+                let number = reference.strip_prefix('#')?;
+                let code = match number.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => number.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+        match (c, reference) {
+            (Some(c), Some(reference)) => {
+                decoded.push(c);
+                rest = &rest[reference.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::{Answers, Verdict};
+    use crate::runner::SOLVERS;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2022</title></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2>
+<p>For example, suppose the Elves finish writing their items' <code>Calories</code>:</p>
+<pre><code>1000
+2000
+
+<em>4000</em>
+</code></pre>
+<p>The <em>Elf</em> carrying the most is carrying <code><em>6000</em></code> Calories &amp; more:</p>
+<pre><code>Elf &lt;1&gt;: 3000
+</code></pre>
+<p>In the example above, this is <em><code>6000</code></em>.</p>
+</article>
+<p>Your puzzle answer was <code>68787</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>In the example above, the total is <code><em>45&#x27;000</em></code>.</p>
+</article>
+</main>
+</body>
+</html>
+"#;
+
+    /// Day 6's page, with most of the story left out but its markup around the example and
+    /// answers kept as is
+    const DAY6_PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 6 - Advent of Code 2022</title></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 6: Tuning Trouble ---</h2>
+<p>For example, suppose you receive the following datastream buffer:</p>
+<pre><code>mjqjpqmgbljsphdztnvjfqwrcgsmlb</code></pre>
+<p>After the first three characters (<code>mjq</code>) have been received, there haven't been enough characters received yet to find the marker.</p>
+<p>The first time a marker appears is after the <em>seventh</em> character arrives. Once it does, the last four characters received are <code>jpqm</code>, which are all different. In this case, your subroutine should report the value <code><em>7</em></code>, because the first start-of-packet marker is complete after 7 characters have been processed.</p>
+<p>Here are a few more examples:</p>
+<ul>
+<li><code>bvwbjplbgvbhsrlpgdmjqwftvncz</code>: first marker after character <code><em>5</em></code></li>
+<li><code>nppdvjthqldpwncqszvftbrmjlhg</code>: first marker after character <code><em>6</em></code></li>
+<li><code>nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg</code>: first marker after character <code><em>10</em></code></li>
+<li><code>zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw</code>: first marker after character <code><em>11</em></code></li>
+</ul>
+<p><em>How many characters need to be processed before the first start-of-packet marker is detected?</em></p>
+</article>
+<p>Your puzzle answer was <code>1538</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>A <em>start-of-message marker</em> is just like a start-of-packet marker, except it consists of <em>14 distinct characters</em> rather than 4.</p>
+<p>Here are the first positions of start-of-message markers for all of the above examples:</p>
+<ul>
+<li><code>mjqjpqmgbljsphdztnvjfqwrcgsmlb</code>: first marker after character <code><em>19</em></code></li>
+<li><code>bvwbjplbgvbhsrlpgdmjqwftvncz</code>: first marker after character <code><em>23</em></code></li>
+<li><code>nppdvjthqldpwncqszvftbrmjlhg</code>: first marker after character <code><em>23</em></code></li>
+<li><code>nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg</code>: first marker after character <code><em>29</em></code></li>
+<li><code>zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw</code>: first marker after character <code><em>26</em></code></li>
+</ul>
+<p><em>How many characters need to be processed before the first start-of-message marker is detected?</em></p>
+</article>
+</main>
+</body>
+</html>
+"#;
+
+    #[test]
+    fn parse_page() {
+        let page = Page::parse(PAGE);
+        assert_eq!(page.day, Some(1));
+        assert_eq!(page.descriptions.len(), 2);
+        assert_eq!(
+            page.descriptions[0].blocks,
+            ["1000\n2000\n\n4000\n", "Elf <1>: 3000\n"]
+        );
+        assert_eq!(page.descriptions[0].emphasized, ["6000", "6000"]);
+        assert_eq!(page.example(), Some("1000\n2000\n\n4000\n"));
+        assert_eq!(page.answers(), [(Part::One, "6000"), (Part::Two, "45'000")]);
+    }
+
+    #[test]
+    fn part_one_only() {
+        let page = Page::parse(&PAGE[..PAGE.find("<p>Your puzzle answer").unwrap()]);
+        assert_eq!(page.answers(), [(Part::One, "6000")]);
+        assert_eq!(Page::parse("<html></html>"), Page::default());
+    }
+
+    /// Extracting day 6's page must give the checked-in example and its recorded answers
+    #[test]
+    fn saved_day6_page() {
+        let page = Page::parse(DAY6_PAGE);
+        assert_eq!(page.day, Some(6));
+        let example =
+            std::fs::read_to_string(crate::input::default_path(6).with_file_name("example.txt"))
+                .unwrap();
+        assert_eq!(page.example(), Some(example.as_str()));
+
+        let answers = Answers::load(&default_path()).unwrap();
+        for (part, answer) in page.answers() {
+            assert_eq!(answers.get(6, part), Some(answer), "part {}", part);
+        }
+        assert_eq!(page.answers().len(), 2);
+    }
+
+    /// Every day's example must still give the answers recorded from its puzzle page
+    #[test]
+    fn recorded_examples() {
+        let answers = Answers::load(&default_path()).unwrap();
+        for solver in SOLVERS {
+            let path = crate::input::default_path(solver.day).with_file_name("example.txt");
+            let example = std::fs::read_to_string(path).unwrap();
+            let run = solver.run(&example, None);
+            for (part, verdict) in answers.check(&run) {
+                assert!(
+                    matches!(verdict, Verdict::Match | Verdict::Unrecorded),
+                    "day {} part {}: {:?}",
+                    solver.day,
+                    part,
+                    verdict
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn decode_entities() {
+        assert_eq!(decode("a &lt;b&gt; &amp;&amp; &#35;&#x41;"), "a <b> && #A");
+        assert_eq!(decode("AT&T & &unknown; &"), "AT&T & &unknown; &");
+    }
+}
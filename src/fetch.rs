@@ -0,0 +1,320 @@
+//! Downloading puzzle input from the Advent of Code site, once per day.
+//!
+//! Puzzle input is personal to each account, so requests are made with the session cookie of a
+//! logged-in browser, read from the `AOC_SESSION` environment variable. Each day's input is saved
+//! to the path [`Source::Default`](crate::input::Source::Default) reads from, and never fetched
+//! again once it's there, so the site is only asked for each input once and everything else works
+//! offline.
+//!
+//! Requests go through a [`Client`], which is [`Ureq`] outside of tests.
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::input;
+
+/// The environment variable holding the session cookie
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Where puzzles are served from
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Makes the HTTP requests for a [`Fetcher`]
+pub trait Client {
+    /// GET `url` with `session` as the session cookie, returning the body of a successful response
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError>;
+}
+
+/// A [`Client`] using `ureq`, identifying itself to the site as this repository
+#[cfg(not(target_arch = "wasm32"))]
+pub struct Ureq;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Client for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+        let response = ureq::get(url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+        let transport = |message: String| FetchError::Transport {
+            url: url.to_string(),
+            message,
+        };
+        match response {
+            Ok(response) => response.into_string().map_err(|e| transport(e.to_string())),
+            Err(ureq::Error::Status(status, _)) => Err(FetchError::Status {
+                url: url.to_string(),
+                status,
+            }),
+            Err(ureq::Error::Transport(e)) => Err(transport(e.to_string())),
+        }
+    }
+}
+
+/// Automated requests are asked to say where they come from
+#[cfg(not(target_arch = "wasm32"))]
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (puzzle input fetcher, one request per day)"
+);
+
+/// Puzzle input for any day, from the cache if it's there and otherwise from the site
+pub struct Fetcher<C> {
+    client: C,
+    session: Option<String>,
+    base_url: String,
+    /// Where to cache input instead of next to each day's binary
+    cache: Option<PathBuf>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Fetcher<Ureq> {
+    /// Fetch from the site with the session cookie in [`SESSION_VAR`], if it's set
+    pub fn from_env() -> Self {
+        let session = std::env::var(SESSION_VAR).ok();
+        Fetcher::new(Ureq, session.filter(|session| !session.trim().is_empty()))
+    }
+}
+
+impl<C: Client> Fetcher<C> {
+    /// Fetch with `client`, caching input next to each day's binary
+    ///
+    /// Without a session, only input that's already cached can be returned.
+    pub fn new(client: C, session: Option<String>) -> Self {
+        Fetcher {
+            client,
+            session: session.map(|session| session.trim().to_string()),
+            base_url: BASE_URL.to_string(),
+            cache: None,
+        }
+    }
+
+    /// Fetch from another server, e.g. one stubbed out for testing
+    pub fn with_base_url(self, base_url: &str) -> Self {
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            ..self
+        }
+    }
+
+    /// Cache each day's input at `dayN/input.txt` under `dir`
+    pub fn with_cache(self, dir: impl Into<PathBuf>) -> Self {
+        Fetcher {
+            cache: Some(dir.into()),
+            ..self
+        }
+    }
+
+    /// Where a day's input is cached
+    pub fn path(&self, day: u8) -> PathBuf {
+        match &self.cache {
+            Some(dir) => dir.join(format!("day{}", day)).join("input.txt"),
+            None => input::default_path(day),
+        }
+    }
+
+    /// Whether a day's input has been cached, so can be had without a request
+    pub fn is_cached(&self, day: u8) -> bool {
+        self.path(day).is_file()
+    }
+
+    /// The URL a day's input is fetched from
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/2022/day/{}/input", self.base_url, day)
+    }
+
+    /// A day's input, fetching and caching it first if it isn't already cached
+    pub fn input(&self, day: u8) -> Result<String, FetchError> {
+        let path = self.path(day);
+        let cache_error = |error| FetchError::Cache {
+            path: path.clone(),
+            error,
+        };
+        if path.is_file() {
+            return fs::read_to_string(&path).map_err(cache_error);
+        }
+
+        let session = self.session.as_deref().ok_or(FetchError::NoSession)?;
+        let input = self.client.get(&self.url(day), session)?;
+
+        // Write to the side and rename, so an interrupted write is never taken for cached input
+        let partial = path.with_extension("txt.part");
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(cache_error)?;
+        }
+        fs::write(&partial, &input).map_err(cache_error)?;
+        fs::rename(&partial, &path).map_err(cache_error)?;
+        Ok(input)
+    }
+}
+
+/// Failure to get a day's input
+#[derive(Debug)]
+pub enum FetchError {
+    /// The input isn't cached, and there's no session to fetch it with
+    NoSession,
+    /// The site responded with an error
+    Status { url: String, status: u16 },
+    /// The site couldn't be reached, or the response couldn't be read
+    Transport { url: String, message: String },
+    /// The cached input couldn't be read or written
+    Cache { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::NoSession => write!(
+                f,
+                "input is not cached, and {} is not set to a session cookie to fetch it with",
+                SESSION_VAR
+            ),
+            FetchError::Status { url, status } => {
+                write!(f, "{} responded with status {}", url, status)?;
+                match status {
+                    400 | 500 => write!(f, " (has the session in {} expired?)", SESSION_VAR),
+                    404 => write!(f, " (is the puzzle unlocked yet?)"),
+                    _ => Ok(()),
+                }
+            }
+            FetchError::Transport { url, message } => {
+                write!(f, "could not fetch {}: {}", url, message)
+            }
+            FetchError::Cache { path, error } => {
+                write!(
+                    f,
+                    "could not cache input at `{}`: {}",
+                    path.display(),
+                    error
+                )
+            }
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Cache { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// A server on a local port answering every request with `status` and `body`, and recording
+    /// each request's path and cookie
+    fn stub_server(status: u16, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let log = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut lines = BufReader::new(&stream).lines().map(Result::unwrap);
+                let path = lines.next().unwrap_or_default();
+                let cookie = lines
+                    .take_while(|line| !line.is_empty())
+                    .find_map(|line| line.strip_prefix("Cookie: ").map(String::from))
+                    .unwrap_or_default();
+                log.lock().unwrap().push(format!("{} {}", path, cookie));
+
+                let response = format!(
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    fn cache_dir(test: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc2022-fetch-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// A client for fetchers that must not make requests
+    struct Offline;
+
+    impl Client for Offline {
+        fn get(&self, url: &str, _: &str) -> Result<String, FetchError> {
+            panic!("unexpected request for {}", url)
+        }
+    }
+
+    #[test]
+    fn fetches_once_then_caches() {
+        let (url, requests) = stub_server(200, "1000\n2000\n");
+        let dir = cache_dir("once");
+        let fetcher = Fetcher::new(Ureq, Some("abc123".to_string()))
+            .with_base_url(&url)
+            .with_cache(&dir);
+
+        assert!(!fetcher.is_cached(1));
+        assert_eq!(fetcher.input(1).unwrap(), "1000\n2000\n");
+        assert!(fetcher.is_cached(1));
+        assert_eq!(fetcher.input(1).unwrap(), "1000\n2000\n");
+
+        let cached = fs::read_to_string(dir.join("day1/input.txt")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(cached, "1000\n2000\n");
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /2022/day/1/input HTTP/1.1 session=abc123"]
+        );
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let (url, requests) = stub_server(400, "Please log in");
+        let dir = cache_dir("errors");
+        let fetcher = Fetcher::new(Ureq, Some("expired".to_string()))
+            .with_base_url(&url)
+            .with_cache(&dir);
+
+        let error = fetcher.input(2).unwrap_err();
+        assert!(matches!(error, FetchError::Status { status: 400, .. }));
+        assert!(error.to_string().contains(SESSION_VAR));
+        assert!(!fetcher.is_cached(2));
+        assert!(fetcher.input(2).is_err());
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn cached_input_needs_no_session() {
+        let dir = cache_dir("offline");
+        fs::create_dir_all(dir.join("day3")).unwrap();
+        fs::write(dir.join("day3/input.txt"), "vJrwpWtwJgWr\n").unwrap();
+        let fetcher = Fetcher::new(Offline, None).with_cache(&dir);
+
+        assert_eq!(fetcher.input(3).unwrap(), "vJrwpWtwJgWr\n");
+        assert!(matches!(fetcher.input(4), Err(FetchError::NoSession)));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn default_cache_is_default_input() {
+        let fetcher = Fetcher::new(Offline, None);
+        assert_eq!(fetcher.path(7), input::default_path(7));
+        assert_eq!(fetcher.url(7), "https://adventofcode.com/2022/day/7/input");
+    }
+}
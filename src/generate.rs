@@ -0,0 +1,513 @@
+//! Seeded generators of random but valid puzzle inputs, for stress-testing the solvers on inputs
+//! far larger than the checked-in ones.
+//!
+//! Each generator takes a random number generator and a `size`, whose meaning depends on the day:
+//! usually the number of lines or records, or the width of a map. The result is accepted by that
+//! day's parser and can be solved by both of its parts.
+use std::collections::BTreeSet;
+
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::day11::{Monkey, Operand, Operation};
+use crate::day13::Packet;
+use crate::day14::{Cave, MAX_DEPTH, SOURCE};
+use crate::day15::Telemetry;
+use crate::day5::{Crate, Move, Procedure};
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+/// The largest coordinate of the distress beacon searched for by day 15's part 2
+const MAX_COORDINATE: i32 = 4_000_000;
+
+/// Generate an input for `day` from `seed`, or `None` if that day has no generator
+///
+/// The same day, seed and size always produce the same input.
+pub fn input(day: u8, seed: u64, size: usize) -> Option<String> {
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let input = match day {
+        1 => calories(rng, size),
+        2 => strategy_guide(rng, size),
+        3 => rucksacks(rng, size),
+        4 => section_assignments(rng, size),
+        5 => procedure(rng, size),
+        6 => datastream(rng, size),
+        7 => terminal_output(rng, size),
+        8 => forest(rng, size),
+        9 => motions(rng, size),
+        10 => program(rng, size),
+        11 => monkeys(rng, size),
+        12 => heightmap(rng, size),
+        13 => packets(rng, size),
+        14 => cave(rng, size),
+        15 => sensors(rng, size, MAX_COORDINATE),
+        _ => return None,
+    };
+    Some(input)
+}
+
+/// Join `lines`, ending each with a newline
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+/// The inventories of `n_elves` elves, separated by blank lines
+pub fn calories(rng: &mut impl Rng, n_elves: usize) -> String {
+    let elves = (0..n_elves.max(1)).map(|_| {
+        let n_items = rng.gen_range(1..=10);
+        lines((0..n_items).map(|_| rng.gen_range(1000..=60000).to_string()))
+    });
+    elves.collect::<Vec<_>>().join("\n")
+}
+
+/// `n_rounds` rounds of rock, paper, scissors
+pub fn strategy_guide(rng: &mut impl Rng, n_rounds: usize) -> String {
+    lines((0..n_rounds).map(|_| {
+        let opponent = rng.gen_range('A'..='C');
+        let response = rng.gen_range('X'..='Z');
+        format!("{} {}", opponent, response)
+    }))
+}
+
+/// Every item type, in order of priority
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `n_groups` groups of three rucksacks
+///
+/// Each group shares exactly one badge, and each rucksack's compartments share exactly one item.
+pub fn rucksacks(rng: &mut impl Rng, n_groups: usize) -> String {
+    let mut rucksacks = vec![];
+    for _ in 0..n_groups {
+        let mut items = ITEMS.to_vec();
+        items.shuffle(rng);
+        // Only the badge may be carried by more than one elf in the group
+        let (&badge, rest) = items.split_first().unwrap();
+        for pool in rest.chunks(rest.len() / 3) {
+            rucksacks.push(rucksack(rng, badge, pool));
+        }
+    }
+    lines(rucksacks)
+}
+
+/// A rucksack holding `badge` and otherwise only items from `pool`
+fn rucksack(rng: &mut impl Rng, badge: u8, pool: &[u8]) -> String {
+    let n_kinds = rng.gen_range(1..=pool.len());
+    let mut kinds: Vec<u8> = pool.choose_multiple(rng, n_kinds).copied().collect();
+    kinds.push(badge);
+    kinds.shuffle(rng);
+
+    let (&shared, rest) = kinds.split_first().unwrap();
+    let (left, right): (Vec<u8>, Vec<u8>) = rest.iter().copied().partition(|_| rng.gen());
+    let len = left.len().max(right.len()) + 1 + rng.gen_range(0..8);
+    let mut items = compartment(rng, shared, &left, len);
+    items.extend(compartment(rng, shared, &right, len));
+    String::from_utf8(items).unwrap()
+}
+
+/// `len` items including `shared` and every one of `kinds`, but nothing else
+fn compartment(rng: &mut impl Rng, shared: u8, kinds: &[u8], len: usize) -> Vec<u8> {
+    let mut items = vec![shared];
+    items.extend(kinds);
+    while items.len() < len {
+        items.push(*kinds.choose(rng).unwrap_or(&shared));
+    }
+    items.shuffle(rng);
+    items
+}
+
+/// `n_pairs` pairs of section ranges
+pub fn section_assignments(rng: &mut impl Rng, n_pairs: usize) -> String {
+    let mut range = || {
+        let start = rng.gen_range(1..=99);
+        let end = rng.gen_range(start..=99);
+        format!("{}-{}", start, end)
+    };
+    lines((0..n_pairs).map(|_| format!("{},{}", range(), range())))
+}
+
+/// Between three and nine stacks of crates, and `n_moves` moves of a few crates at a time that
+/// never take more crates than a stack holds
+pub fn procedure(rng: &mut impl Rng, n_moves: usize) -> String {
+    let n_stacks = rng.gen_range(3..=9);
+    let mut stacks: Vec<Vec<Crate>> = (0..n_stacks)
+        .map(|_| {
+            let height = rng.gen_range(1..=8);
+            (0..height)
+                .map(|_| Crate::new(rng.gen_range('A'..='Z')).unwrap())
+                .collect()
+        })
+        .collect();
+    let initial = stacks.clone();
+    let mut moves = vec![];
+    for _ in 0..n_moves {
+        let occupied: Vec<usize> = (0..n_stacks).filter(|&i| !stacks[i].is_empty()).collect();
+        let from = *occupied.choose(rng).unwrap();
+        let mut to = rng.gen_range(0..n_stacks - 1);
+        if to >= from {
+            to += 1;
+        }
+        let n_items = rng.gen_range(1..=stacks[from].len().min(5));
+        let remaining = stacks[from].len() - n_items;
+        let moved = stacks[from].split_off(remaining);
+        stacks[to].extend(moved);
+        moves.push(Move {
+            from: from + 1,
+            to: to + 1,
+            n_items,
+        });
+    }
+
+    Procedure {
+        stacks: initial,
+        moves,
+    }
+    .to_string()
+}
+
+/// A datastream of `len` characters with a start-of-message marker at a random position
+pub fn datastream(rng: &mut impl Rng, len: usize) -> String {
+    const MARKER_LEN: usize = 14;
+    // Eight letters are too few for a start-of-message marker to appear by chance
+    let mut stream: Vec<u8> = (0..len.max(MARKER_LEN))
+        .map(|_| rng.gen_range(b'a'..=b'h'))
+        .collect();
+    let at = rng.gen_range(0..=stream.len() - MARKER_LEN);
+    let letters: Vec<u8> = (b'a'..=b'z').collect();
+    let marker = letters.choose_multiple(rng, MARKER_LEN).copied();
+    stream.splice(at..at + MARKER_LEN, marker);
+    String::from_utf8(stream).unwrap() + "\n"
+}
+
+/// A transcript exploring a random directory tree of `n_entries` files and directories
+pub fn terminal_output(rng: &mut impl Rng, n_entries: usize) -> String {
+    let mut transcript = vec!["$ cd /".to_string()];
+    explore(rng, &mut transcript, n_entries);
+    lines(transcript)
+}
+
+/// List the current directory, then change into and explore each of its subdirectories, so that
+/// `n_entries` are listed in all
+fn explore(rng: &mut impl Rng, transcript: &mut Vec<String>, n_entries: usize) {
+    transcript.push("$ ls".to_string());
+    let n_listed = rng.gen_range(1..=10).min(n_entries);
+    let mut remaining = n_entries - n_listed;
+
+    let mut names = BTreeSet::new();
+    while names.len() < n_listed {
+        let len = rng.gen_range(1..=8);
+        names.insert(
+            (0..len)
+                .map(|_| rng.gen_range('a'..='z'))
+                .collect::<String>(),
+        );
+    }
+
+    let mut subdirectories = vec![];
+    for (i, name) in names.into_iter().enumerate() {
+        // There must be somewhere to list the remaining entries
+        if (i == 0 && remaining > 0) || rng.gen_ratio(1, 3) {
+            transcript.push(format!("dir {}", name));
+            subdirectories.push(name);
+        } else {
+            let extension = ["", ".txt", ".dat", ".log"].choose(rng).unwrap();
+            // Mostly small files, so that some directories are small too
+            let size = 10f64.powf(rng.gen_range(2.0..5.5)) as usize;
+            transcript.push(format!("{} {}{}", size, name, extension));
+        }
+    }
+
+    subdirectories.shuffle(rng);
+    let n_subdirectories = subdirectories.len();
+    for (i, name) in subdirectories.into_iter().enumerate() {
+        // Share the remaining entries out roughly evenly, so the tree stays shallow
+        let share = match n_subdirectories - i {
+            1 => remaining,
+            n => rng.gen_range(0..=2 * remaining / n).min(remaining),
+        };
+        remaining -= share;
+        transcript.push(format!("$ cd {}", name));
+        explore(rng, transcript, share);
+        transcript.push("$ cd ..".to_string());
+    }
+}
+
+/// A square forest `size` trees across
+pub fn forest(rng: &mut impl Rng, size: usize) -> String {
+    let forest = Grid::from_fn(size.max(1), size.max(1), |_| rng.gen_range(0..=9));
+    forest.to_string() + "\n"
+}
+
+/// `n_motions` motions of the rope's head
+pub fn motions(rng: &mut impl Rng, n_motions: usize) -> String {
+    lines((0..n_motions).map(|_| {
+        let direction = ['U', 'D', 'L', 'R'].choose(rng).unwrap();
+        format!("{} {}", direction, rng.gen_range(1..=20))
+    }))
+}
+
+/// A program of `n_instructions` instructions
+///
+/// The sprite wanders back and forth but stays on the screen.
+pub fn program(rng: &mut impl Rng, n_instructions: usize) -> String {
+    let mut x = 1;
+    lines((0..n_instructions).map(|_| match rng.gen_ratio(1, 3) {
+        true => "noop".to_string(),
+        false => {
+            let next = (x + rng.gen_range(-10..=10)).clamp(0, 39);
+            let addend = next - x;
+            x = next;
+            format!("addx {}", addend)
+        }
+    }))
+}
+
+/// Between three and eight monkeys holding `n_items` items between them
+///
+/// As in the puzzle, one monkey squares worry levels, one multiplies them, and the rest add to
+/// them, and each tests divisibility by a distinct prime. So that worry levels stay in range
+/// over part 1's rounds, nothing is thrown to the monkey that squares them, and the other
+/// multiplies by no more than 7.
+pub fn monkeys(rng: &mut impl Rng, n_items: usize) -> String {
+    const PRIMES: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+    let n_monkeys = rng.gen_range(3..=PRIMES.len());
+    let divisors: Vec<u64> = PRIMES.choose_multiple(rng, n_monkeys).copied().collect();
+
+    let mut operations = vec![
+        Operation::Mul(Operand::Old),
+        Operation::Mul(Operand::Value(rng.gen_range(2..=7))),
+    ];
+    while operations.len() < n_monkeys {
+        operations.push(Operation::Add(Operand::Value(rng.gen_range(1..=8))));
+    }
+    operations.shuffle(rng);
+    let squarer = operations
+        .iter()
+        .position(|&op| op == Operation::Mul(Operand::Old))
+        .unwrap() as u64;
+
+    let mut items = vec![vec![]; n_monkeys];
+    for _ in 0..n_items {
+        items[rng.gen_range(0..n_monkeys)].push(rng.gen_range(50..=99));
+    }
+
+    let monkeys = (0..n_monkeys as u64)
+        .zip(divisors)
+        .zip(operations)
+        .zip(items);
+    let monkeys = monkeys.map(|(((id, divisor), operation), items)| {
+        let others: Vec<u64> = (0..n_monkeys as u64)
+            .filter(|&m| m != id && m != squarer)
+            .collect();
+        let partners: Vec<u64> = others.choose_multiple(rng, 2).copied().collect();
+        Monkey {
+            id,
+            divisor,
+            operation,
+            items: items.into(),
+            // With only one other monkey to throw to, both throws go to it
+            partners: [partners[0], *partners.last().unwrap()],
+        }
+    });
+    monkeys.map(|monkey| monkey.to_string()).join("\n\n") + "\n"
+}
+
+/// A heightmap `width` squares across (at least 26), with a path from the start up to the
+/// best signal
+///
+/// The path winds from the left edge to the right, climbing from `a` to `z` as it goes; the rest
+/// of the map is random.
+pub fn heightmap(rng: &mut impl Rng, width: usize) -> String {
+    let width = width.max(26);
+    let height = rng.gen_range(5..=41);
+    let mut heights = Grid::from_fn(width, height, |_| rng.gen_range('a'..='z'));
+
+    // Wander up or down each column before stepping right, so the path never crosses itself
+    let mut row = rng.gen_range(0..height);
+    let mut path = vec![];
+    for col in 0..width {
+        let next = rng.gen_range(0..height);
+        let rows: Vec<usize> = match next < row {
+            true => (next..=row).rev().collect(),
+            false => (row..=next).collect(),
+        };
+        path.extend(rows.into_iter().map(|row| (row, col)));
+        row = next;
+    }
+
+    // Climb one letter at each of 25 distinct steps along the path
+    let climbs: BTreeSet<usize> = rand::seq::index::sample(rng, path.len() - 1, 25)
+        .into_iter()
+        .map(|i| i + 1)
+        .collect();
+    let mut letter = b'a';
+    for (i, &position) in path.iter().enumerate() {
+        if climbs.contains(&i) {
+            letter += 1;
+        }
+        heights[position] = letter as char;
+    }
+    heights[path[0]] = 'S';
+    heights[*path.last().unwrap()] = 'E';
+
+    heights.to_string() + "\n"
+}
+
+/// `n_pairs` pairs of packets, separated by blank lines
+pub fn packets(rng: &mut impl Rng, n_pairs: usize) -> String {
+    let pairs = (0..n_pairs.max(1)).map(|_| format!("{}\n{}\n", list(rng, 3), list(rng, 3)));
+    pairs.collect::<Vec<_>>().join("\n")
+}
+
+/// A list packet nested at most `depth` lists deep
+fn list(rng: &mut impl Rng, depth: usize) -> Packet {
+    let len = rng.gen_range(0..=5);
+    let packets = (0..len).map(|_| match depth > 0 && rng.gen_ratio(1, 3) {
+        true => list(rng, depth - 1),
+        false => Packet::Int(rng.gen_range(0..=10)),
+    });
+    Packet::List(packets.collect())
+}
+
+/// A cave scan of `n_paths` paths of rock, within reach of the falling sand and growing deeper
+/// the more paths there are, down to [`MAX_DEPTH`]
+pub fn cave(rng: &mut impl Rng, n_paths: usize) -> String {
+    // Leave room above the rock for sand to pile up
+    const TOP: i32 = 10;
+    // Roughly one square in five is rock, given paths around a dozen squares long
+    let depth = (2 * TOP + rng.gen_range(6..=10) * (n_paths as f64).sqrt() as i32).min(MAX_DEPTH);
+    let paths = (0..n_paths).map(|_| {
+        // Sand spreads out as it falls, so spread rock out evenly over the triangle it can reach
+        let y = TOP + ((depth - TOP) as f64 * rng.gen::<f64>().sqrt()) as i32;
+        let spread = y.min(SOURCE.x - 10);
+        let mut point = Point::new(SOURCE.x + rng.gen_range(-spread..=spread), y);
+        let mut path = vec![point];
+        // Alternate between horizontal and vertical lines
+        for i in 0..rng.gen_range(1..=5) {
+            let length = rng.gen_range(-8..=8);
+            point += match i % 2 {
+                0 => Point::new(length.max(-point.x), 0),
+                _ => Point::new(0, length.clamp(TOP - point.y, MAX_DEPTH - point.y)),
+            };
+            path.push(point);
+        }
+        path
+    });
+    Cave {
+        paths: paths.collect(),
+    }
+    .to_string()
+}
+
+/// Telemetry from `n_sensors` sensors (at least four) that leave exactly one position uncovered
+/// with both coordinates in `0..=max_coordinate`
+///
+/// Rotated 45°, each sensor's coverage is a square, so four large sensors are placed to cover
+/// everything either side of the gap's diagonal and either side of the gap along it. The rest
+/// are placed at random, each reaching short of the gap.
+pub fn sensors(rng: &mut impl Rng, n_sensors: usize, max_coordinate: i32) -> String {
+    let gap = Point::new(
+        rng.gen_range(0..=max_coordinate),
+        rng.gen_range(0..=max_coordinate),
+    );
+    // Rotated coordinates, in which each sensor covers a square of "radius" its distance
+    let (u, v) = (gap.x + gap.y, gap.x - gap.y);
+    let rotated = |u: i32, v: i32| Point::new((u + v) / 2, (u - v) / 2);
+    // The smallest distance of at least `min` that centers a square on integer coordinates
+    let radius = |min: i32, parity: i32| min + (min - parity).rem_euclid(2);
+
+    let r = radius(max_coordinate, u - 1);
+    let mut telemetry = vec![
+        telemetry_at(rotated(u - 1 - r, 0), r as u32, rng),
+        telemetry_at(rotated(u + 1 + r, 0), r as u32, rng),
+    ];
+    let r = radius(max_coordinate, 1);
+    telemetry.push(telemetry_at(rotated(u, v + 1 + r), r as u32, rng));
+    telemetry.push(telemetry_at(rotated(u, v - 1 - r), r as u32, rng));
+
+    while telemetry.len() < n_sensors {
+        let sensor = Point::new(
+            rng.gen_range(0..=max_coordinate),
+            rng.gen_range(0..=max_coordinate),
+        );
+        let reach = sensor.manhattan_distance(gap);
+        if reach > 1 {
+            let distance = rng.gen_range(1..reach);
+            telemetry.push(telemetry_at(sensor, distance, rng));
+        }
+    }
+    telemetry.shuffle(rng);
+
+    lines(telemetry.iter().map(Telemetry::to_string))
+}
+
+/// A sensor whose closest beacon is at a random position `distance` away
+fn telemetry_at(sensor: Point, distance: u32, rng: &mut impl Rng) -> Telemetry {
+    let d = distance as i32;
+    let dx = rng.gen_range(-d..=d);
+    let dy = (d - dx.abs()) * [-1, 1].choose(rng).unwrap();
+    Telemetry {
+        sensor,
+        beacon: sensor + Point::new(dx, dy),
+        distance,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day15::{self, Day15};
+    use crate::runner::SOLVERS;
+    use crate::Solution;
+
+    #[test]
+    fn every_day_is_solvable() {
+        // Day 15's huge sensors are too slow to solve without optimizations; see `exactly_one_gap`
+        for solver in SOLVERS.iter().filter(|solver| solver.day != 15) {
+            for seed in 0..4 {
+                let input = input(solver.day, seed, 200).unwrap();
+                let run = solver.run(&input, None);
+                assert_eq!(run.error(), None, "day {} seed {}", solver.day, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn smallest_inputs_are_solvable() {
+        for solver in SOLVERS {
+            for size in [0, 1] {
+                let input = input(solver.day, 7, size).unwrap();
+                let run = solver.run(&input, None);
+                assert_eq!(run.error(), None, "day {} size {}", solver.day, size);
+            }
+        }
+    }
+
+    #[test]
+    fn seeds_are_reproducible() {
+        assert_eq!(input(7, 42, 100), input(7, 42, 100));
+        assert_ne!(input(7, 42, 100), input(7, 43, 100));
+        assert_eq!(input(16, 42, 100), None);
+    }
+
+    #[test]
+    fn exactly_one_gap() {
+        for seed in 0..4 {
+            let rng = &mut StdRng::seed_from_u64(seed);
+            let max = 30;
+            let telemetry = Day15::parse(&sensors(rng, 10, max)).unwrap();
+            let gaps: Vec<Point> = (0..=max)
+                .flat_map(|x| (0..=max).map(move |y| Point::new(x, y)))
+                .filter(|&p| {
+                    telemetry
+                        .iter()
+                        .all(|t| t.sensor.manhattan_distance(p) > t.distance)
+                })
+                .collect();
+            assert_eq!(gaps.len(), 1, "seed {}", seed);
+            let expected = gaps[0].x as isize * 4000000 + gaps[0].y as isize;
+            assert_eq!(day15::tuning_frequency(&telemetry, max), Some(expected));
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Points, vectors and directions on the integer plane.
+//!
+//! Like [`Grid`](crate::grid::Grid), the y axis points down, so [`Direction::Up`] decreases y
+//! and a point's `(y, x)` is its grid [`Position`].
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::grid::{Position, Step};
+
+/// A point on the plane, or the vector between two points
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// The displacement between two [`Point`]s
+pub type Vector = Point;
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    /// The sign of each component: a unit step (possibly diagonal) in the same direction
+    pub fn signum(self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Restrict each component to `min..=max`
+    pub fn clamp(self, min: i32, max: i32) -> Self {
+        Point::new(self.x.clamp(min, max), self.y.clamp(min, max))
+    }
+
+    // https://en.wikipedia.org/wiki/Taxicab_geometry
+    pub fn manhattan_distance(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    // https://en.wikipedia.org/wiki/Chebyshev_distance
+    pub fn chebyshev_distance(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The grid position of this point, if neither coordinate is negative
+    pub fn position(self) -> Option<Position> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+}
+
+/// Panics if the position is too far from the origin to be a point
+impl From<Position> for Point {
+    fn from((row, col): Position) -> Self {
+        let coordinate = |n: usize| i32::try_from(n).expect("grid position out of range");
+        Point::new(coordinate(col), coordinate(row))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Self) -> Self::Output {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: i32) -> Self::Output {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// One of the four orthogonal directions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// A unit vector pointing this way
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Right => Point::new(1, 0),
+        }
+    }
+
+    /// The `(rows, columns)` step pointing this way within a grid
+    pub fn step(self) -> Step {
+        let Point { x, y } = self.vector();
+        (y as isize, x as isize)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseDirectionError;
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    /// Parse the initial of a direction: R, L, U or D
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(ParseDirectionError),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    /// Write the initial of the direction, as parsed
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let initial = match self {
+            Direction::Up => "U",
+            Direction::Down => "D",
+            Direction::Left => "L",
+            Direction::Right => "R",
+        };
+        write!(f, "{}", initial)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let mut p = Point::new(3, -4);
+        assert_eq!(p + Point::new(1, 1), Point::new(4, -3));
+        assert_eq!(p - Point::new(1, 1), Point::new(2, -5));
+        assert_eq!(-p * 2, Point::new(-6, 8));
+        assert_eq!(p.signum(), Point::new(1, -1));
+        assert_eq!(p.clamp(-2, 2), Point::new(2, -2));
+        p += Direction::Up.vector();
+        assert_eq!(p, Point::new(3, -5));
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point::new(2, 18), Point::new(-2, 15));
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!(Point::new(3, 1).position(), Some((1, 3)));
+        assert_eq!(Point::new(-1, 1).position(), None);
+        assert_eq!(Point::from((1, 3)), Point::new(3, 1));
+        assert_eq!(Direction::Up.step(), (-1, 0));
+    }
+
+    #[test]
+    fn parse_direction() {
+        assert_eq!("R".parse(), Ok(Direction::Right));
+        assert_eq!("X".parse::<Direction>(), Err(ParseDirectionError));
+    }
+}
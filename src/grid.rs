@@ -0,0 +1,355 @@
+//! A dense 2D grid for the puzzles whose input is a map of characters.
+//!
+//! Cells are addressed by `(row, column)` positions, counting from the top left.
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::{self, Diagnostic, Location};
+
+/// A `(row, column)` position within a [`Grid`]
+pub type Position = (usize, usize);
+
+/// A step of `(rows, columns)` from one position to another
+pub type Step = (isize, isize);
+
+/// Steps to the neighbours sharing an edge: up, down, left and right
+pub const ORTHOGONAL: [Step; 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Steps to every neighbour, including diagonals, clockwise from up
+pub const ALL_DIRECTIONS: [Step; 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, stored row by row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// A `width` by `height` grid with each cell computed from its position
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse a map with one character per cell and one line per row
+    ///
+    /// Every line must have as many characters as the first, and `cell` must accept every
+    /// character.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseGridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (j, c) in line.char_indices() {
+                if width.is_some_and(|width| row_width == width) {
+                    return Err(ParseGridError::RaggedRow(Location::new(i, line, j)));
+                }
+                let value = cell(c)
+                    .ok_or_else(|| ParseGridError::InvalidCell(Location::new(i, line, j)))?;
+                cells.push(value);
+                row_width += 1;
+            }
+            match width {
+                Some(width) if row_width < width => {
+                    return Err(ParseGridError::RaggedRow(Location::new(
+                        i,
+                        line,
+                        line.len(),
+                    )));
+                }
+                Some(_) => {}
+                None => width = Some(row_width),
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseGridError::Empty),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, col): Position) -> Option<&T> {
+        self.contains((row, col))
+            .then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Position) -> Option<&mut T> {
+        match self.contains((row, col)) {
+            true => Some(&mut self.cells[row * self.width + col]),
+            false => None,
+        }
+    }
+
+    pub fn contains(&self, (row, col): Position) -> bool {
+        row < self.height && col < self.width
+    }
+
+    /// The position one `step` away from `position`, if it is still within the grid
+    pub fn step(&self, (row, col): Position, (drow, dcol): Step) -> Option<Position> {
+        let row = row.checked_add_signed(drow)?;
+        let col = col.checked_add_signed(dcol)?;
+        self.contains((row, col)).then_some((row, col))
+    }
+
+    /// The cells of one row, from left to right
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// The cells of one column, from top to bottom
+    ///
+    /// Panics if there is no such column.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(col < self.width, "column {} out of bounds", col);
+        // A grid without rows has no cells to start the column from
+        let cells = self.cells.get(col..).unwrap_or_default();
+        cells.iter().step_by(self.width)
+    }
+
+    /// Every row, from top to bottom, even if the grid has no columns
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// The position of the first cell, row by row, that satisfies `predicate`
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// The cells reached by repeatedly taking `step` from `from` (excluding `from` itself)
+    /// until leaving the grid
+    pub fn ray(&self, from: Position, step: Step) -> impl Iterator<Item = &T> + '_ {
+        std::iter::successors(self.step(from, step), move |&position| {
+            self.step(position, step)
+        })
+        .map(|position| &self[position])
+    }
+
+    /// The positions of the up to 4 neighbours sharing an edge with `position`
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |step| self.step(position, step))
+    }
+
+    /// The positions of the up to 8 neighbours surrounding `position`, including diagonals
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |step| self.step(position, step))
+    }
+
+    /// A grid of the same shape with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {:?} out of bounds", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {:?} out of bounds", position))
+    }
+}
+
+/// Renders each row on its own line, with no separator between cells
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A character map that couldn't be parsed into a [`Grid`]
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    /// A character that doesn't represent a cell
+    InvalidCell(Location),
+    /// A row whose length differs from the first row's
+    RaggedRow(Location),
+    /// Input without any cells
+    Empty,
+}
+
+impl Diagnostic for ParseGridError {
+    fn hint(&self) -> String {
+        match self {
+            ParseGridError::InvalidCell(_) => "unexpected character".to_string(),
+            ParseGridError::RaggedRow(_) => {
+                "expected the same number of cells in every row".to_string()
+            }
+            ParseGridError::Empty => "map contains no cells".to_string(),
+        }
+    }
+
+    fn location(&self) -> Option<&Location> {
+        match self {
+            ParseGridError::InvalidCell(loc) | ParseGridError::RaggedRow(loc) => Some(loc),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        error::describe(self, f)
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseGridError> {
+        Grid::parse(input, |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            digits("123\n4x6\n"),
+            Err(ParseGridError::InvalidCell(Location::new(1, "4x6", 1)))
+        );
+        assert_eq!(
+            digits("123\n45\n"),
+            Err(ParseGridError::RaggedRow(Location::new(1, "45", 2)))
+        );
+        assert_eq!(
+            digits("123\n4567\n"),
+            Err(ParseGridError::RaggedRow(Location::new(1, "4567", 3)))
+        );
+        assert_eq!(digits(""), Err(ParseGridError::Empty));
+    }
+
+    #[test]
+    fn rows_columns_and_rays() {
+        let grid = digits("123\n456\n789").unwrap();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.ray((1, 1), (0, -1)).collect::<Vec<_>>(), vec![&4]);
+        assert_eq!(grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(), vec![&5, &9]);
+        assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+        assert_eq!(grid.position(|&d| d == 8), Some((2, 1)));
+    }
+
+    #[test]
+    fn empty_grids() {
+        for grid in [Grid::new(0, 3, 0), Grid::from_fn(0, 3, |_| 0)] {
+            assert_eq!((grid.width(), grid.height()), (0, 3));
+            assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[] as &[u32]; 3]);
+            assert_eq!(grid.iter().count(), 0);
+            assert_eq!(grid.get((0, 0)), None);
+            assert_eq!(grid.to_string(), "\n\n");
+        }
+
+        let grid = Grid::from_fn(3, 0, |_| 0);
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.column(2).count(), 0);
+        assert_eq!(grid.positions().count(), 0);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::new(3, 3, ());
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+    }
+}
@@ -0,0 +1,187 @@
+//! Locating, reading and normalizing puzzle input, independent of the current working directory.
+use std::borrow::Cow;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where to read a day's puzzle input from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// An explicit file path
+    Path(PathBuf),
+    /// Standard input, selected with `-`
+    Stdin,
+    /// The day's `input.txt`, next to the source of its binary
+    Default(u8),
+}
+
+impl Source {
+    /// Select the source named by a command line argument, falling back to the day's default
+    ///
+    /// `-` selects stdin and anything else is taken as a file path.
+    pub fn new(day: u8, arg: Option<&str>) -> Self {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+            None => Source::Default(day),
+        }
+    }
+
+    /// Select the source named by the first command line argument of this process
+    pub fn from_args(day: u8) -> Self {
+        Self::new(day, env::args().nth(1).as_deref())
+    }
+
+    /// The name to give the input in diagnostics: its path, or `-` for stdin
+    pub fn name(&self) -> String {
+        match self {
+            Source::Path(path) => path.display().to_string(),
+            Source::Stdin => "-".to_string(),
+            Source::Default(day) => default_path(*day).display().to_string(),
+        }
+    }
+
+    /// Read the entire input
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Source::Path(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            Source::Default(day) => fs::read_to_string(default_path(*day)),
+        };
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "`{}`", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Default(day) => write!(f, "`{}`", default_path(*day).display()),
+        }
+    }
+}
+
+/// The well-known location of a day's input: `src/bin/dayN/input.txt` in this crate
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/bin")
+        .join(format!("day{}", day))
+        .join("input.txt")
+}
+
+/// Every file directly inside `dir`, sorted by name, for running a day against each in turn
+///
+/// Subdirectories and hidden files (whose names start with `.`) are skipped.
+pub fn batch(dir: &Path) -> Result<Vec<Source>, InputError> {
+    let error = |error| InputError {
+        source: Source::Path(dir.to_path_buf()),
+        error,
+    };
+
+    let mut paths = vec![];
+    for entry in fs::read_dir(dir).map_err(error)? {
+        let entry = entry.map_err(error)?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if !hidden && entry.file_type().map_err(error)?.is_file() {
+            paths.push(entry.path());
+        }
+    }
+    paths.sort();
+    Ok(paths.into_iter().map(Source::Path).collect())
+}
+
+/// Failure to read puzzle input, naming the source that was tried
+#[derive(Debug)]
+pub struct InputError {
+    pub source: Source,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "could not read input from {}: {}",
+            self.source, self.error
+        )
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Normalize line endings and whitespace, so that every parser sees the same input however the
+/// file was saved
+///
+/// CRLF line endings become LF, trailing whitespace is removed from every line (leading
+/// whitespace is significant to some days), and the input ends with exactly one newline, unless
+/// it is empty.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let trimmed = input.trim_end();
+    let is_normal = input.len() == trimmed.len() + 1
+        && input.ends_with('\n')
+        && trimmed
+            .lines()
+            .all(|line| line.len() == line.trim_end().len())
+        && !trimmed.contains('\r');
+    if is_normal || input.is_empty() {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalized = String::with_capacity(input.len());
+    for line in trimmed.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    Cow::Owned(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_line_endings_and_whitespace() {
+        assert_eq!(normalize("a \r\n\r\n  b\t\r\n\r\n"), "a\n\n  b\n");
+        assert_eq!(normalize("a\nb"), "a\nb\n");
+        assert_eq!(normalize("   \n"), "");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn batch_lists_files_in_order() {
+        let dir = env::temp_dir().join(format!("aoc2022-batch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        for name in ["b.txt", "a.txt", ".hidden", "nested/c.txt"] {
+            fs::write(dir.join(name), "1\n").unwrap();
+        }
+
+        let sources = batch(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            sources,
+            vec![
+                Source::Path(dir.join("a.txt")),
+                Source::Path(dir.join("b.txt"))
+            ]
+        );
+    }
+
+    #[test]
+    fn normal_input_is_borrowed() {
+        assert!(matches!(normalize("a\n\n  b\n"), Cow::Borrowed(_)));
+    }
+}
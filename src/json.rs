@@ -0,0 +1,159 @@
+//! Machine-readable solver results, for feeding answers into other tools.
+//!
+//! Each [`Run`] becomes an object of the form
+//!
+//! ```json
+//! {
+//!   "day": 10,
+//!   "title": "Cathode-Ray Tube",
+//!   "read_ns": 21000,
+//!   "parse_ns": 7600,
+//!   "parse_memory": { "allocations": 3, "peak_bytes": 2048 },
+//!   "error": null,
+//!   "parts": [
+//!     { "part": 1, "answer": { "type": "integer", "value": 16880 }, "solve_ns": 897, "memory": null, "error": null },
+//!     { "part": 2, "answer": { "type": "image", "value": ["###..", "#..#."] }, "solve_ns": 1600, "memory": null, "error": null }
+//!   ]
+//! }
+//! ```
+//!
+//! where `error` is set (and `answer` is null) if the input couldn't be parsed or a part couldn't
+//! be solved. Only the parts that were run are listed. Heap usage is null unless allocations are
+//! being counted (see [`memory`](crate::memory)).
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::memory::Usage;
+use crate::runner::{Outcome, Part, Run};
+
+/// An array with an object for each run
+pub fn runs(runs: &[Run]) -> Value {
+    Value::Array(runs.iter().map(run).collect())
+}
+
+/// An array with an object for each run of a batch, like [`run`]'s with the name of the input
+/// file added as `file`
+pub fn batch(runs: &[(String, Run)]) -> Value {
+    let values = runs.iter().map(|(file, run)| {
+        let mut value = self::run(run);
+        value["file"] = json!(file);
+        value
+    });
+    Value::Array(values.collect())
+}
+
+/// A single day's results
+pub fn run(run: &Run) -> Value {
+    let parts: Vec<Value> = [(Part::One, &run.part1), (Part::Two, &run.part2)]
+        .into_iter()
+        .filter_map(|(part, outcome)| Some(self::part(part, outcome.as_ref()?)))
+        .collect();
+
+    json!({
+        "day": run.day,
+        "title": run.title,
+        "read_ns": nanoseconds(run.read),
+        "parse_ns": nanoseconds(run.parse),
+        "parse_memory": run.parse_memory.map(memory),
+        "error": run.error,
+        "parts": parts,
+    })
+}
+
+fn part(part: Part, outcome: &Outcome) -> Value {
+    let (answer, error) = match &outcome.answer {
+        Ok(answer) => (self::answer(answer), None),
+        Err(e) => (Value::Null, Some(e)),
+    };
+
+    json!({
+        "part": part.number(),
+        "answer": answer,
+        "solve_ns": nanoseconds(outcome.elapsed),
+        "memory": outcome.memory.map(memory),
+        "error": error,
+    })
+}
+
+fn memory(usage: Usage) -> Value {
+    json!({ "allocations": usage.allocations, "peak_bytes": usage.peak })
+}
+
+/// An answer typed by its contents: an integer, a multi-line image (as an array of rows), or
+/// otherwise a string
+pub fn answer(answer: &str) -> Value {
+    if answer.contains('\n') {
+        return json!({ "type": "image", "value": answer.lines().collect::<Vec<_>>() });
+    }
+    if let Ok(n) = answer.parse::<i64>() {
+        return json!({ "type": "integer", "value": n });
+    }
+    if let Ok(n) = answer.parse::<u64>() {
+        return json!({ "type": "integer", "value": n });
+    }
+    json!({ "type": "string", "value": answer })
+}
+
+fn nanoseconds(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner;
+
+    #[test]
+    fn typed_answers() {
+        assert_eq!(answer("-42"), json!({ "type": "integer", "value": -42 }));
+        assert_eq!(
+            answer("VQZNJMWTR"),
+            json!({ "type": "string", "value": "VQZNJMWTR" })
+        );
+        assert_eq!(
+            answer("#.\n.#"),
+            json!({ "type": "image", "value": ["#.", ".#"] })
+        );
+    }
+
+    #[test]
+    fn run_with_error() {
+        let run = runner::solver(2).unwrap().run("A Y\nB Q\n", None);
+        let value = self::run(&run);
+        assert_eq!(value["day"], 2);
+        assert_eq!(value["parts"], json!([]));
+        assert_eq!(
+            value["error"],
+            "expected `A|B|C X|Y|Z` at line 2, column 3: `B Q`"
+        );
+    }
+
+    #[test]
+    fn batch_names_files() {
+        let solver = runner::solver(2).unwrap();
+        let runs = [
+            ("a.txt".to_string(), solver.run("A Y\n", None)),
+            ("b.txt".to_string(), solver.run("A Q\n", None)),
+        ];
+        let value = batch(&runs);
+        assert_eq!(value[0]["file"], "a.txt");
+        assert_eq!(value[0]["error"], Value::Null);
+        assert_eq!(value[1]["file"], "b.txt");
+        assert!(value[1]["error"].is_string());
+    }
+
+    #[test]
+    fn run_one_part() {
+        let example = include_str!("bin/day1/example.txt");
+        let run = runner::solver(1).unwrap().run(example, Some(Part::Two));
+        let value = self::run(&run);
+        assert_eq!(value["error"], Value::Null);
+        assert_eq!(value["parts"].as_array().unwrap().len(), 1);
+        assert_eq!(value["parts"][0]["part"], 2);
+        assert_eq!(
+            value["parts"][0]["answer"],
+            json!({ "type": "integer", "value": 45000 })
+        );
+    }
+}
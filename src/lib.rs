@@ -0,0 +1,40 @@
+//! Advent of Code 2022 in Rust.
+//!
+//! Each day's puzzle lives in its own module and implements [`Solution`]. The [`runner`] module
+//! erases each day's types so the `aoc` binary can select days at runtime, and the binaries under
+//! `src/bin/dayN` each call [`run_main`] to read a day's input (see [`input`]) and print its
+//! answers.
+
+pub mod animation;
+pub mod answers;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod memory;
+pub mod parse;
+pub mod runner;
+pub mod simulation;
+mod solution;
+pub mod watch;
+
+pub use solution::{run_main, Solution};
@@ -1,3 +1,528 @@
-fn main() {
-    println!("Welcome to AOC 2022! Use `cargo run --bin dayN` to run a specific day's challenge.");
+/// Advent of Code 2022 runner
+///
+/// Usage: aoc [DAY|all] [PART] [--input PATH|-] [--batch DIR] [--check] [--json] [--parallel]
+///
+/// With no day (or `all`), every implemented day is run in order and a summary table of answers
+/// and timings is printed. A single day may be narrowed down to one part, and read from an
+/// explicit input path or stdin instead of the day's `input.txt`.
+///
+/// With `--check`, answers are compared against those recorded in `answers.toml` instead, and
+/// any mismatches are reported per day and part.
+///
+/// With `--json`, results are printed as a JSON array with an object per day instead (see
+/// [`aoc2022::json`]), including days whose input couldn't be read or parsed.
+///
+/// With `--batch`, a single day is run against every file in a directory instead, e.g. inputs
+/// from several accounts, and a table with a row per file is printed. Files that can't be parsed
+/// are flagged in the table and their errors shown in full below it.
+///
+/// With `--parallel`, every selected day is solved concurrently on its own thread; results are
+/// still reported in order of day, though timings are skewed by days competing for CPUs. In
+/// either case a day that panics is reported as an error rather than aborting the others.
+use std::env;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc2022::answers::{self, Answers, Verdict};
+use aoc2022::input::{self, InputError, Source};
+use aoc2022::json;
+use aoc2022::memory::{self, Usage};
+use aoc2022::runner::{self, Outcome, Part, Run, Solver};
+
+const USAGE: &str =
+    "Usage: aoc [DAY|all] [PART] [--input PATH|-] [--batch DIR] [--check] [--json] [--parallel]";
+
+#[derive(Debug, Default)]
+struct Args {
+    day: Option<u8>,
+    part: Option<Part>,
+    input: Option<String>,
+    batch: Option<String>,
+    check: bool,
+    json: bool,
+    parallel: bool,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        let mut positional = 0;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-i" | "--input" => {
+                    let path = args.next().ok_or(format!("{} requires a path", arg))?;
+                    parsed.input = Some(path);
+                }
+                "--batch" => {
+                    let dir = args.next().ok_or(format!("{} requires a directory", arg))?;
+                    parsed.batch = Some(dir);
+                }
+                "--check" => parsed.check = true,
+                "--json" => parsed.json = true,
+                "--parallel" => parsed.parallel = true,
+                "all" if positional == 0 => positional += 1,
+                day if positional == 0 => {
+                    let day = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+                    parsed.day = Some(day);
+                    positional += 1;
+                }
+                part if positional == 1 => {
+                    let part = part
+                        .parse()
+                        .map_err(|_| format!("invalid part '{}'", part))?;
+                    parsed.part = Some(part);
+                    positional += 1;
+                }
+                unexpected => return Err(format!("unexpected argument '{}'", unexpected)),
+            }
+        }
+
+        if parsed.day.is_none() && (parsed.part.is_some() || parsed.input.is_some()) {
+            return Err("a part or input path can only be given for a single day".to_string());
+        }
+        if parsed.check && parsed.json {
+            return Err("--check and --json can't be combined".to_string());
+        }
+        if parsed.batch.is_some() {
+            if parsed.day.is_none() {
+                return Err("--batch requires a single day".to_string());
+            }
+            if parsed.input.is_some() || parsed.check || parsed.parallel {
+                return Err(
+                    "--batch can't be combined with --input, --check or --parallel".to_string(),
+                );
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let solvers = match args.day {
+        Some(day) => match runner::solver(day) {
+            Some(solver) => vec![solver],
+            None => {
+                eprintln!("error: day {} is not implemented", day);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => runner::SOLVERS.iter().collect(),
+    };
+
+    if args.check {
+        return check(&solvers, &args);
+    }
+    if let Some(dir) = &args.batch {
+        return batch(solvers[0], Path::new(dir), &args);
+    }
+
+    let runs = run_days(&solvers, &args);
+    match (args.json, args.day) {
+        (true, _) => println!("{:#}", json::runs(&runs)),
+        (false, Some(_)) => runs
+            .iter()
+            .for_each(|run| print_run(run, &Source::new(run.day, args.input.as_deref()))),
+        (false, None) => print_summary(&runs),
+    }
+
+    match runs.iter().any(|run| run.error().is_some()) {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+/// Read each day's input and solve it, one day after another or all at once with `--parallel`
+fn run_days(solvers: &[&Solver], args: &Args) -> Vec<Run> {
+    let run = |solver: &Solver| {
+        let source = Source::new(solver.day, args.input.as_deref());
+        run_source(solver, &source, args)
+    };
+    match args.parallel {
+        true => runner::in_parallel(solvers, run),
+        false => solvers.iter().map(|solver| run(solver)).collect(),
+    }
+}
+
+/// Read a day's input from `source` and solve it
+fn run_source(solver: &Solver, source: &Source, args: &Args) -> Run {
+    let start = Instant::now();
+    match source.read() {
+        Ok(input) => Run {
+            read: start.elapsed(),
+            ..solver.run(&input, args.part)
+        },
+        Err(e) => unread(solver, &e),
+    }
+}
+
+/// A run that failed before it started, because its input couldn't be read
+fn unread(solver: &Solver, error: &InputError) -> Run {
+    Run {
+        day: solver.day,
+        title: solver.title,
+        read: Duration::ZERO,
+        parse: Duration::ZERO,
+        parse_memory: None,
+        error: Some(error.to_string()),
+        report: None,
+        part1: None,
+        part2: None,
+    }
+}
+
+/// Run the selected days (or every day) and compare their answers to the recorded ones
+fn check(solvers: &[&Solver], args: &Args) -> ExitCode {
+    let answers = match Answers::load(&answers::default_path()) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut passed, mut failed, mut unrecorded) = (0, 0, 0);
+    for run in run_days(solvers, args) {
+        if let Some(e) = &run.error {
+            println!("day {}: {}", run.day, e);
+            failed += 1;
+            continue;
+        }
+
+        for (part, verdict) in answers.check(&run) {
+            match verdict {
+                Verdict::Match => passed += 1,
+                Verdict::Mismatch { expected, actual } => {
+                    failed += 1;
+                    match expected.contains('\n') || actual.contains('\n') {
+                        true => println!(
+                            "day {} part {}: expected\n{}\ngot\n{}",
+                            run.day, part, expected, actual
+                        ),
+                        false => println!(
+                            "day {} part {}: expected {}, got {}",
+                            run.day, part, expected, actual
+                        ),
+                    }
+                }
+                Verdict::Unrecorded => {
+                    unrecorded += 1;
+                    println!("day {} part {}: no recorded answer", run.day, part);
+                }
+                Verdict::Failed(e) => {
+                    failed += 1;
+                    println!("day {} part {}: {}", run.day, part, e);
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} unrecorded",
+        passed, failed, unrecorded
+    );
+    match failed {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+/// Print a single day's answers, as the day's own binary would, after the time taken to read and
+/// parse its input from `source`
+fn print_run(run: &Run, source: &Source) {
+    println!("Day {}: {}", run.day, run.title);
+    println!("Read: {:.1?}", run.read);
+    println!("Parse: {}", cost(run.parse, run.parse_memory));
+    match (&run.report, &run.error) {
+        (Some(report), _) => eprint!("{}", report.render(&source.name())),
+        (None, Some(e)) => eprintln!("error: {}", e),
+        (None, None) => {}
+    }
+    for (part, outcome) in [(1, &run.part1), (2, &run.part2)] {
+        let Some(Outcome {
+            answer,
+            elapsed,
+            memory,
+        }) = outcome
+        else {
+            continue;
+        };
+        let cost = cost(*elapsed, *memory);
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(e) => {
+                eprintln!("error: part {}: {}", part, e);
+                continue;
+            }
+        };
+        match answer.contains('\n') {
+            true => println!("Part {}: ({})\n{}", part, cost, answer),
+            false => println!("Part {}: {} ({})", part, answer, cost),
+        }
+    }
+}
+
+/// A phase's time, followed by its heap usage if allocations are being counted
+fn cost(elapsed: Duration, memory: Option<Usage>) -> String {
+    match memory {
+        Some(usage) => format!(
+            "{:.1?}, {} allocations, {} peak",
+            elapsed,
+            usage.allocations,
+            bytes(usage.peak)
+        ),
+        None => format!("{:.1?}", elapsed),
+    }
+}
+
+/// A number of bytes in the largest binary unit that keeps it above 1
+fn bytes(n: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if n < 1024 {
+        return format!("{} B", n);
+    }
+    let mut size = n as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// Print a table of every day's answers and timings, and another of their heap usage if
+/// allocations are being counted
+///
+/// Multi-line answers (e.g. day 10's CRT image) don't fit in a table cell, so they are printed
+/// in full below the tables, along with any errors.
+fn print_summary(runs: &[Run]) {
+    let mut images = vec![];
+    let mut errors = vec![];
+
+    println!(
+        "{:>3}  {:<24}  {:>10}  {:>10}  {:>16}  {:>10}  {:>16}  {:>10}",
+        "Day", "Title", "Read", "Parse", "Part 1", "Time", "Part 2", "Time"
+    );
+    for run in runs {
+        if let Some(e) = &run.error {
+            errors.push(format!("day {}: {}", run.day, e));
+        }
+        let cells = part_cells(run);
+        images.extend(
+            cells
+                .images
+                .iter()
+                .map(|&(part, image)| (run.day, part, image)),
+        );
+        for (part, e) in cells.errors {
+            errors.push(format!("day {} part {}: {}", run.day, part, e));
+        }
+        println!(
+            "{:>3}  {:<24}  {:>10}  {:>10}  {}",
+            run.day,
+            run.title,
+            format!("{:.1?}", run.read),
+            format!("{:.1?}", run.parse),
+            cells.row
+        );
+    }
+
+    let total: Duration = runs
+        .iter()
+        .flat_map(|run| [&run.part1, &run.part2])
+        .flatten()
+        .map(|outcome| outcome.elapsed)
+        .chain(runs.iter().flat_map(|run| [run.read, run.parse]))
+        .sum();
+    println!("Total: {:.1?}", total);
+
+    if memory::ENABLED {
+        print_memory(runs);
+    }
+
+    for (day, part, image) in images {
+        println!("\nDay {} Part {}:\n{}", day, part, image);
+    }
+    for e in errors {
+        eprintln!("error: {}", e);
+    }
+}
+
+/// The answer and time cells of a table row for both parts, with the multi-line answers and
+/// errors that don't fit in a cell, by part, to print below the table
+struct PartCells<'a> {
+    row: String,
+    images: Vec<(u8, &'a str)>,
+    errors: Vec<(u8, &'a str)>,
+}
+
+fn part_cells(run: &Run) -> PartCells<'_> {
+    let mut images = vec![];
+    let mut errors = vec![];
+    let mut cells = vec![];
+    for (part, outcome) in [(1, &run.part1), (2, &run.part2)] {
+        let (answer, elapsed) = match outcome {
+            Some(Outcome {
+                answer: Ok(answer),
+                elapsed,
+                ..
+            }) if answer.contains('\n') => {
+                images.push((part, answer.as_str()));
+                ("(see below)", *elapsed)
+            }
+            Some(Outcome {
+                answer: Ok(answer),
+                elapsed,
+                ..
+            }) => (answer.as_str(), *elapsed),
+            Some(Outcome {
+                answer: Err(e),
+                elapsed,
+                ..
+            }) => {
+                errors.push((part, e.as_str()));
+                ("error", *elapsed)
+            }
+            None => ("-", Duration::ZERO),
+        };
+        cells.push(format!(
+            "{:>16}  {:>10}",
+            answer,
+            format!("{:.1?}", elapsed)
+        ));
+    }
+    PartCells {
+        row: cells.join("  "),
+        images,
+        errors,
+    }
+}
+
+/// Print a table of the allocations made and peak heap growth while parsing and solving each day
+fn print_memory(runs: &[Run]) {
+    println!(
+        "\n{:>3}  {:>12}  {:>10}  {:>12}  {:>10}  {:>12}  {:>10}",
+        "Day", "Parse allocs", "Peak", "Part 1", "Peak", "Part 2", "Peak"
+    );
+    for run in runs {
+        let phases = [
+            run.parse_memory,
+            run.part1.as_ref().and_then(|outcome| outcome.memory),
+            run.part2.as_ref().and_then(|outcome| outcome.memory),
+        ];
+        let cells: Vec<String> = phases
+            .iter()
+            .map(|usage| match usage {
+                Some(usage) => format!("{:>12}  {:>10}", usage.allocations, bytes(usage.peak)),
+                None => format!("{:>12}  {:>10}", "-", "-"),
+            })
+            .collect();
+        println!("{:>3}  {}", run.day, cells.join("  "));
+    }
+}
+
+/// Run a single day against every file in `dir`, one file after another
+fn batch(solver: &Solver, dir: &Path, args: &Args) -> ExitCode {
+    let sources = match input::batch(dir) {
+        Ok(sources) => sources,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let runs: Vec<(String, Run)> = sources
+        .iter()
+        .map(|source| (source.name(), run_source(solver, source, args)))
+        .collect();
+    match args.json {
+        true => println!("{:#}", json::batch(&runs)),
+        false => print_batch(solver, &runs),
+    }
+
+    match runs.iter().any(|(_, run)| run.error().is_some()) {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+/// Print a table of each file's answers and timings, flagging those that couldn't be parsed or
+/// solved, followed by multi-line answers and errors in full
+fn print_batch(solver: &Solver, runs: &[(String, Run)]) {
+    let mut images = vec![];
+    let mut errors = vec![];
+
+    let width = runs
+        .iter()
+        .map(|(file, _)| file.len())
+        .chain(["File".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!("Day {}: {}", solver.day, solver.title);
+    println!(
+        "{:<width$}  {:>10}  {:>10}  {:>16}  {:>10}  {:>16}  {:>10}  Status",
+        "File",
+        "Read",
+        "Parse",
+        "Part 1",
+        "Time",
+        "Part 2",
+        "Time",
+        width = width
+    );
+    for (file, run) in runs {
+        let status = match (&run.report, &run.error, run.error()) {
+            (Some(report), _, _) => {
+                errors.push(report.render(file));
+                "parse error"
+            }
+            (None, Some(e), _) => {
+                errors.push(format!("error: {}: {}\n", file, e));
+                "error"
+            }
+            (None, None, Some(_)) => "solve error",
+            (None, None, None) => "ok",
+        };
+
+        let cells = part_cells(run);
+        images.extend(
+            cells
+                .images
+                .iter()
+                .map(|&(part, image)| (file, part, image)),
+        );
+        for (part, e) in cells.errors {
+            errors.push(format!("error: {} part {}: {}\n", file, part, e));
+        }
+        println!(
+            "{:<width$}  {:>10}  {:>10}  {}  {}",
+            file,
+            format!("{:.1?}", run.read),
+            format!("{:.1?}", run.parse),
+            cells.row,
+            status,
+            width = width
+        );
+    }
+
+    let failed = runs.iter().filter(|(_, run)| run.error().is_some()).count();
+    println!("{} files, {} failed", runs.len(), failed);
+
+    for (file, part, image) in images {
+        println!("\n{} Part {}:\n{}", file, part, image);
+    }
+    for e in errors {
+        eprint!("\n{}", e);
+    }
 }
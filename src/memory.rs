@@ -0,0 +1,113 @@
+//! Heap statistics from a counting global allocator, enabled with the `alloc-stats` feature.
+//!
+//! With the feature on, every allocation in the process goes through [`Counting`], and
+//! [`measure`] reports how many allocations a closure made and how far the heap grew above its
+//! size at the start. Without it, [`measure`] just calls the closure and reports nothing.
+//!
+//! The counts are process-wide, so they include other threads' allocations when days are run
+//! with `--parallel`.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering::Relaxed};
+
+/// Whether allocations are being counted
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Heap usage while running some code
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// How many allocations were made, counting reallocations
+    pub allocations: u64,
+    /// The most the heap grew above its size at the start, in bytes
+    pub peak: usize,
+}
+
+/// The system allocator, counting allocations and the bytes currently allocated
+pub struct Counting;
+
+// Safety: every call is passed straight on to the system allocator
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            match new_size.checked_sub(layout.size()) {
+                Some(more) => grow(more),
+                None => {
+                    CURRENT.fetch_sub(layout.size() - new_size, Relaxed);
+                }
+            }
+        }
+        new_ptr
+    }
+}
+
+fn grow(size: usize) {
+    let current = CURRENT.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(current, Relaxed);
+}
+
+/// Call `f`, along with its heap usage if allocations are being counted
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !ENABLED {
+        return (f(), None);
+    }
+
+    let start = CURRENT.load(Relaxed);
+    let allocations = ALLOCATIONS.load(Relaxed);
+    PEAK.store(start, Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        peak: PEAK.load(Relaxed).saturating_sub(start),
+    };
+    (result, Some(usage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_allocations() {
+        let (bytes, usage) = measure(|| vec![0u8; 4096]);
+        assert_eq!(bytes.len(), 4096);
+        assert_eq!(usage.is_some(), ENABLED);
+        if let Some(usage) = usage {
+            assert!(usage.allocations >= 1);
+            assert!(usage.peak >= 4096);
+        }
+    }
+}
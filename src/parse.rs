@@ -0,0 +1,117 @@
+//! Reusable [`nom`] parsers for the pieces that recur across puzzle inputs.
+//!
+//! Parsers report failures as byte offsets into their input via [`finish`], which
+//! [`Location::at`](crate::error::Location::at) turns into a line and column.
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending, space0},
+    combinator::{all_consuming, map, map_res, opt, recognize},
+    error::Error,
+    multi::{separated_list0, separated_list1},
+    sequence::{pair, preceded, separated_pair, terminated, tuple},
+    IResult, Parser,
+};
+
+use crate::geometry::Point;
+
+/// Run `parser` over the whole of `input`, or return the byte offset at which it failed
+pub fn finish<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, O, Error<&'a str>>,
+) -> Result<O, usize> {
+    match all_consuming(parser)(input) {
+        Ok((_, output)) => Ok(output),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(input.len() - e.input.len()),
+        Err(nom::Err::Incomplete(_)) => Err(input.len()),
+    }
+}
+
+/// An unsigned decimal integer
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// A decimal integer with an optional leading `-`
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+/// A position of the form `x=<int>, y=<int>`
+pub fn coordinates(input: &str) -> IResult<&str, Point> {
+    map(
+        separated_pair(
+            preceded(tag("x="), signed),
+            tag(", "),
+            preceded(tag("y="), signed),
+        ),
+        |(x, y)| Point::new(x, y),
+    )(input)
+}
+
+/// Zero or more `item`s separated by commas, each optionally followed by spaces
+pub fn comma_list<'a, O>(
+    item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list0(pair(char(','), space0), item)
+}
+
+/// One or more `block`s separated by blank lines
+pub fn blocks<'a, O>(
+    block: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(line_ending, line_ending), block)
+}
+
+/// A line of the form `<indent><label>: <value>`, not including its line ending
+pub fn labelled<'a, O>(
+    label: &'static str,
+    value: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    preceded(tuple((space0, tag(label), char(':'), space0)), value)
+}
+
+/// `item` followed by the end of its line, or the end of the input
+pub fn line<'a, O>(
+    item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    terminated(item, opt(line_ending))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(finish("-42", signed::<i32>), Ok(-42));
+        assert_eq!(finish("42", unsigned::<u8>), Ok(42));
+        assert_eq!(finish("-42", unsigned::<u8>), Err(0));
+        assert_eq!(finish("4200", unsigned::<u8>), Err(0));
+        assert_eq!(finish("42x", signed::<i32>), Err(2));
+    }
+
+    #[test]
+    fn coordinates_and_lists() {
+        assert_eq!(finish("x=-2, y=15", coordinates), Ok(Point::new(-2, 15)));
+        assert_eq!(finish("x=-2, z=15", coordinates), Err(6));
+        assert_eq!(
+            finish("79, 98,3", comma_list(unsigned::<u64>)),
+            Ok(vec![79, 98, 3])
+        );
+        assert_eq!(finish("", comma_list(unsigned::<u64>)), Ok(vec![]));
+    }
+
+    #[test]
+    fn blocks_and_fields() {
+        let field = labelled("Test", preceded(tag("divisible by "), unsigned::<u64>));
+        assert_eq!(finish("  Test: divisible by 23", field), Ok(23));
+
+        let block = pair(line(signed::<i32>), signed::<i32>);
+        assert_eq!(
+            finish("1\n2\n\n3\n4", blocks(block)),
+            Ok(vec![(1, 2), (3, 4)])
+        );
+    }
+}
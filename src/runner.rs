@@ -0,0 +1,352 @@
+//! Type-erased access to every day's [`Solution`], so days can be selected at runtime.
+use std::cell::{Cell, RefCell};
+use std::fmt::{self, Display};
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::Once;
+use std::thread;
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use crate::day1::Day1;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::day12::Day12;
+use crate::day13::Day13;
+use crate::day14::Day14;
+use crate::day15::Day15;
+use crate::day2::Day2;
+use crate::day3::Day3;
+use crate::day4::Day4;
+use crate::day5::Day5;
+use crate::day6::Day6;
+use crate::day7::Day7;
+use crate::day8::Day8;
+use crate::day9::Day9;
+use crate::error::Report;
+use crate::memory::{self, Usage};
+use crate::Solution;
+
+/// Every implemented day, in order
+pub static SOLVERS: &[Solver] = &[
+    Solver::new::<Day1>(),
+    Solver::new::<Day2>(),
+    Solver::new::<Day3>(),
+    Solver::new::<Day4>(),
+    Solver::new::<Day5>(),
+    Solver::new::<Day6>(),
+    Solver::new::<Day7>(),
+    Solver::new::<Day8>(),
+    Solver::new::<Day9>(),
+    Solver::new::<Day10>(),
+    Solver::new::<Day11>(),
+    Solver::new::<Day12>(),
+    Solver::new::<Day13>(),
+    Solver::new::<Day14>(),
+    Solver::new::<Day15>(),
+];
+
+/// Look up the solver for `day`, if that day is implemented
+pub fn solver(day: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// The part's number, 1 or 2
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsePartError;
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(ParsePartError),
+        }
+    }
+}
+
+/// A day's [`Solution`] with its input and answer types erased
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub day: u8,
+    pub title: &'static str,
+    run: fn(&str, Option<Part>) -> Run,
+}
+
+impl Solver {
+    pub const fn new<S: Solution>() -> Self {
+        Solver {
+            day: S::DAY,
+            title: S::TITLE,
+            run: run::<S>,
+        }
+    }
+
+    /// Parse `input` and solve the selected part, or both parts if `part` is `None`
+    ///
+    /// A panic while parsing or solving is caught and reported as that phase's error.
+    pub fn run(&self, input: &str, part: Option<Part>) -> Run {
+        (self.run)(input, part)
+    }
+}
+
+/// Answers, timings and heap usage from running a single day
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub day: u8,
+    pub title: &'static str,
+    /// Time taken to read the input, which is up to the caller to fill in
+    pub read: Duration,
+    pub parse: Duration,
+    /// Heap usage while parsing, if allocations are being counted (see [`memory`])
+    pub parse_memory: Option<Usage>,
+    /// Why the input could not be read or parsed, in which case neither part was solved
+    pub error: Option<String>,
+    /// The parse error in detail, for rendering with [`Report::render`]
+    pub report: Option<Report>,
+    pub part1: Option<Outcome>,
+    pub part2: Option<Outcome>,
+}
+
+impl Run {
+    /// The first error from parsing or solving, if any
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref().or_else(|| {
+            [&self.part1, &self.part2]
+                .into_iter()
+                .flatten()
+                .find_map(|outcome| outcome.answer.as_ref().err())
+                .map(String::as_str)
+        })
+    }
+}
+
+/// A part's answer, or why it couldn't be solved, and the wall-clock time it took
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+    /// Heap usage while solving, if allocations are being counted (see [`memory`])
+    pub memory: Option<Usage>,
+}
+
+fn run<S: Solution>(input: &str, part: Option<Part>) -> Run {
+    let start = Instant::now();
+    let (parsed, parse_memory) = memory::measure(|| isolated(|| Ok(S::parse(input))));
+    let parse = start.elapsed();
+
+    let mut run = Run {
+        day: S::DAY,
+        title: S::TITLE,
+        read: Duration::ZERO,
+        parse,
+        parse_memory,
+        error: None,
+        report: None,
+        part1: None,
+        part2: None,
+    };
+
+    match parsed {
+        Ok(Ok(parsed)) => {
+            let solve1 = matches!(part, None | Some(Part::One));
+            let solve2 = matches!(part, None | Some(Part::Two));
+            run.part1 = solve1.then(|| timed(|| S::part1(&parsed)));
+            run.part2 = solve2.then(|| timed(|| S::part2(&parsed)));
+        }
+        Ok(Err(e)) => {
+            run.error = Some(e.to_string());
+            run.report = Some(Report::new(&e));
+        }
+        Err(e) => run.error = Some(e),
+    }
+
+    run
+}
+
+fn timed<T: Display, E: Display>(solve: impl FnOnce() -> Result<T, E>) -> Outcome {
+    let start = Instant::now();
+    let (answer, memory) =
+        memory::measure(|| isolated(|| solve().map(|a| a.to_string()).map_err(|e| e.to_string())));
+    let elapsed = start.elapsed();
+    Outcome {
+        answer,
+        elapsed,
+        memory,
+    }
+}
+
+thread_local! {
+    /// Whether this thread is within [`isolated`], so its panics are reported rather than printed
+    static ISOLATED: Cell<bool> = const { Cell::new(false) };
+    /// Where this thread last panicked within [`isolated`]
+    static PANICKED_AT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Install a panic hook that stays quiet on threads within [`isolated`], recording where they
+/// panicked instead, and leaves other panics to the hook that was there before
+fn install_quiet_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if ISOLATED.with(Cell::get) {
+                let location = info.location().map(ToString::to_string);
+                PANICKED_AT.with(|at| *at.borrow_mut() = location);
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Call `f`, turning a panic into an error describing it
+///
+/// The panic isn't printed to stderr, where it would garble the table or JSON of results.
+fn isolated<T>(f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    install_quiet_hook();
+    let outer = ISOLATED.with(|isolated| isolated.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    ISOLATED.with(|isolated| isolated.set(outer));
+
+    result.unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown cause");
+        match PANICKED_AT.with(RefCell::take) {
+            Some(location) => Err(format!("panicked at {}: {}", location, message)),
+            None => Err(format!("panicked: {}", message)),
+        }
+    })
+}
+
+/// Call `run` for every solver concurrently, each on its own thread, returning the runs in the
+/// same order as `solvers`
+///
+/// Panics within a solver are reported as errors in its run, so one day can't abort the others.
+pub fn in_parallel(solvers: &[&Solver], run: impl Fn(&Solver) -> Run + Sync) -> Vec<Run> {
+    thread::scope(|scope| {
+        let run = &run;
+        let handles: Vec<_> = solvers
+            .iter()
+            .map(|&solver| (solver, scope.spawn(move || run(solver))))
+            .collect();
+        handles
+            .into_iter()
+            .map(|(solver, handle)| {
+                handle.join().unwrap_or_else(|_| Run {
+                    day: solver.day,
+                    title: solver.title,
+                    read: Duration::ZERO,
+                    parse: Duration::ZERO,
+                    parse_memory: None,
+                    error: Some("panicked".to_string()),
+                    report: None,
+                    part1: None,
+                    part2: None,
+                })
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses a number, but panics on part 2
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Panics";
+
+        type Input = u32;
+        type Part1 = u32;
+        type Part2 = u32;
+        type Error = Report;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            input.trim().parse().map_err(|_| Report {
+                hint: "expected a number".to_string(),
+                location: None,
+            })
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Part1, Self::Error> {
+            Ok(input * 2)
+        }
+
+        fn part2(_: &Self::Input) -> Result<Self::Part2, Self::Error> {
+            panic!("Not found")
+        }
+    }
+
+    #[test]
+    fn panics_are_isolated() {
+        let run = Solver::new::<Panics>().run("21", None);
+        assert_eq!(run.error, None);
+        assert_eq!(run.part1.unwrap().answer, Ok("42".to_string()));
+        let error = run.part2.unwrap().answer.unwrap_err();
+        assert!(error.starts_with("panicked at src/runner.rs:"), "{}", error);
+        assert!(error.ends_with(": Not found"), "{}", error);
+    }
+
+    #[test]
+    fn parallel_runs_keep_their_order() {
+        let panics = Solver::new::<Panics>();
+        let solvers: Vec<&Solver> = SOLVERS.iter().rev().chain([&panics]).collect();
+        let runs = in_parallel(&solvers, |solver| solver.run("", None));
+        let days: Vec<u8> = runs.iter().map(|run| run.day).collect();
+        let expected: Vec<u8> = solvers.iter().map(|solver| solver.day).collect();
+        assert_eq!(days, expected);
+        assert!(runs.last().unwrap().error.is_some());
+    }
+
+    /// Windows line endings and stray trailing whitespace mustn't change any day's answers
+    #[test]
+    fn untidy_examples() {
+        for solver in SOLVERS {
+            let path = crate::input::default_path(solver.day).with_file_name("example.txt");
+            let example = std::fs::read_to_string(path).unwrap();
+            let untidy = example.trim_end().replace('\n', " \r\n") + "\t";
+
+            let answers = |input: &str| {
+                let run = solver.run(input, None);
+                let part1 = run.part1.map(|outcome| outcome.answer);
+                let part2 = run.part2.map(|outcome| outcome.answer);
+                (run.error, part1, part2)
+            };
+            assert_eq!(answers(&example), answers(&untidy), "day {}", solver.day);
+        }
+    }
+}
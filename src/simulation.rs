@@ -0,0 +1,78 @@
+//! Step-driven simulations, so that tracing and visualization can be written once for every day
+//! whose puzzle plays out one step at a time.
+//!
+//! Those days implement [`Simulation`] for whatever plays out, such as day 5's crane or day 12's
+//! search, and solve their parts by running it to completion.
+
+/// A process that advances one step at a time until it is done
+pub trait Simulation {
+    /// A copy of the state after a step, as handed to observers
+    type Snapshot: Clone;
+
+    /// Why the simulation couldn't advance
+    type Error;
+
+    /// Advance by a single step
+    ///
+    /// Stepping a simulation that is already done has no effect.
+    fn step(&mut self) -> Result<(), Self::Error>;
+
+    /// Whether the simulation has run to completion
+    fn is_done(&self) -> bool;
+
+    /// The current state
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Step until done
+    fn run(&mut self) -> Result<(), Self::Error> {
+        while !self.is_done() {
+            self.step()?;
+        }
+        Ok(())
+    }
+
+    /// Step until done, calling `observe` with a snapshot of the state after every step
+    fn run_observed(
+        &mut self,
+        mut observe: impl FnMut(&Self::Snapshot),
+    ) -> Result<(), Self::Error> {
+        while !self.is_done() {
+            self.step()?;
+            observe(&self.snapshot());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts down to zero
+    struct Countdown(u32);
+
+    impl Simulation for Countdown {
+        type Snapshot = u32;
+        type Error = ();
+
+        fn step(&mut self) -> Result<(), Self::Error> {
+            self.0 = self.0.saturating_sub(1);
+            Ok(())
+        }
+
+        fn is_done(&self) -> bool {
+            self.0 == 0
+        }
+
+        fn snapshot(&self) -> Self::Snapshot {
+            self.0
+        }
+    }
+
+    #[test]
+    fn observes_every_step() {
+        let mut snapshots = vec![];
+        Countdown(3).run_observed(|&n| snapshots.push(n)).unwrap();
+        assert_eq!(snapshots, vec![2, 1, 0]);
+    }
+}
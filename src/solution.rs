@@ -0,0 +1,76 @@
+use std::fmt::Display;
+use std::process::ExitCode;
+
+use crate::error::{Diagnostic, Report};
+use crate::input::Source;
+
+/// A day's puzzle, split into a parsing phase and a solving phase per part.
+///
+/// The parsed input is computed once and shared by both parts, so parts must not assume they run
+/// in any particular order. Malformed input is reported through the day's [`Solution::Error`]
+/// rather than a panic, pointing at where the input went wrong if it can.
+pub trait Solution {
+    /// Puzzle day, 1-25
+    const DAY: u8;
+
+    /// Puzzle title, as shown on the puzzle page
+    const TITLE: &'static str;
+
+    /// Puzzle input, parsed into the day's domain types
+    type Input;
+
+    /// Part 1 answer
+    type Part1: Display;
+
+    /// Part 2 answer
+    type Part2: Display;
+
+    /// Why the input could not be parsed or solved
+    type Error: Diagnostic;
+
+    /// Parse raw puzzle input
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    /// Solve Part 1
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Self::Error>;
+
+    /// Solve Part 2
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Self::Error>;
+
+    /// Parse raw puzzle input and solve both parts
+    fn solve(input: &str) -> Result<(Self::Part1, Self::Part2), Self::Error> {
+        let input = Self::parse(input)?;
+        Ok((Self::part1(&input)?, Self::part2(&input)?))
+    }
+}
+
+/// The `main` of each day's binary: solve the day's input, named by the first command line
+/// argument as for [`Source::from_args`], and print both answers
+///
+/// Multi-line answers (e.g. day 10's CRT image) start on a line of their own. Input that can't be
+/// read or solved is reported on stderr, and the process fails.
+pub fn run_main<S: Solution>() -> ExitCode {
+    let source = Source::from_args(S::DAY);
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let (part1, part2) = match S::solve(&input) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprint!("{}", Report::new(&e).render(&source.name()));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for (part, answer) in [(1, part1.to_string()), (2, part2.to_string())] {
+        match answer.contains('\n') {
+            true => println!("Part {}:\n{}", part, answer),
+            false => println!("Part {}: {}", part, answer),
+        }
+    }
+    ExitCode::SUCCESS
+}
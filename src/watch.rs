@@ -0,0 +1,135 @@
+//! Re-solving a day as its input and example files change, for the `watch` binary.
+//!
+//! The binary waits for files in the day's directory to be written and hands each run here to be
+//! compared with the last run on the same file, so that a change to a solver or an example shows
+//! up as a changed answer or a faster or slower phase.
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::runner::{Outcome, Run};
+
+/// Whether a file is one that's re-solved when it changes: `input.txt` or an `example*.txt`
+pub fn is_watched(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    name == "input.txt" || (name.starts_with("example") && name.ends_with(".txt"))
+}
+
+/// Every watched file directly inside `dir`, sorted by name
+pub fn files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths = vec![];
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() && is_watched(&entry.path()) {
+            paths.push(entry.path());
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// How much slower (`+`) or faster (`-`) `after` was than `before`, e.g. `-1.2µs, -8%`
+pub fn delta(before: Duration, after: Duration) -> String {
+    let (sign, difference) = match after.checked_sub(before) {
+        Some(slower) => ('+', slower),
+        None => ('-', before - after),
+    };
+    match before.is_zero() {
+        true => format!("{}{:.1?}", sign, difference),
+        false => {
+            let percent = difference.as_secs_f64() / before.as_secs_f64() * 100.0;
+            format!("{}{:.1?}, {}{:.0}%", sign, difference, sign, percent)
+        }
+    }
+}
+
+/// The answers and timings of `run` on `file`, with each timing's change since the `previous`
+/// run on the same file and any answer that changed marked with what it was
+///
+/// Multi-line answers are shown in full below the table, and errors left to the caller.
+pub fn render(file: &str, previous: Option<&Run>, run: &Run) -> String {
+    let mut out = String::new();
+    let timing = |before: Option<Duration>, after: Duration| match before {
+        Some(before) => format!("{:>10.1?}  ({})", after, delta(before, after)),
+        None => format!("{:>10.1?}", after),
+    };
+
+    writeln!(out, "Day {}: {} ({})", run.day, run.title, file).unwrap();
+    let parse = timing(previous.map(|previous| previous.parse), run.parse);
+    writeln!(out, "  {:<8}{:>24}  {}", "Parse", "", parse).unwrap();
+
+    let mut images = vec![];
+    let parts = [
+        (1, previous.and_then(|p| p.part1.as_ref()), &run.part1),
+        (2, previous.and_then(|p| p.part2.as_ref()), &run.part2),
+    ];
+    for (part, before, outcome) in parts {
+        let Some(Outcome {
+            answer, elapsed, ..
+        }) = outcome
+        else {
+            continue;
+        };
+
+        let mut shown = match answer {
+            Ok(answer) if answer.contains('\n') => {
+                images.push((part, answer));
+                "(see below)".to_string()
+            }
+            Ok(answer) => answer.clone(),
+            Err(_) => "error".to_string(),
+        };
+        let before_answer = before.and_then(|before| before.answer.as_ref().ok());
+        if let (Ok(answer), Some(was)) = (answer, before_answer) {
+            if answer != was && !was.contains('\n') {
+                shown = format!("{} (was {})", shown, was);
+            }
+        }
+        let timing = timing(before.map(|before| before.elapsed), *elapsed);
+        writeln!(out, "  Part {:<3}{:>24}  {}", part, shown, timing).unwrap();
+    }
+
+    for (part, image) in images {
+        writeln!(out, "Part {}:\n{}", part, image).unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner;
+
+    #[test]
+    fn watched_files() {
+        assert!(is_watched(Path::new("src/bin/day1/input.txt")));
+        assert!(is_watched(Path::new("example.txt")));
+        assert!(is_watched(Path::new("example2.txt")));
+        assert!(!is_watched(Path::new("main.rs")));
+        assert!(!is_watched(Path::new(".example.txt.swp")));
+        assert!(!is_watched(Path::new("4913")));
+    }
+
+    #[test]
+    fn timing_deltas() {
+        let ms = Duration::from_millis;
+        assert_eq!(delta(ms(10), ms(12)), "+2.0ms, +20%");
+        assert_eq!(delta(ms(10), ms(5)), "-5.0ms, -50%");
+        assert_eq!(delta(Duration::ZERO, ms(1)), "+1.0ms");
+    }
+
+    #[test]
+    fn render_changed_answer() {
+        let solver = runner::solver(2).unwrap();
+        let before = solver.run("A Y\n", None);
+        let after = solver.run("A Y\nB X\n", None);
+        let rendered = render("example.txt", Some(&before), &after);
+        assert!(rendered.starts_with("Day 2: Rock Paper Scissors (example.txt)\n"));
+        assert!(rendered.contains("9 (was 8)"), "{}", rendered);
+        assert_eq!(rendered.matches('%').count(), 3, "{}", rendered);
+    }
+}